@@ -0,0 +1,416 @@
+//! Generates the market rule tables from the TOML files under `data/`.
+//!
+//! Each `data/<market>/<calendar>.toml` becomes
+//! `$OUT_DIR/<market>/<calendar>.rs`, a `calendar!` invocation (or a
+//! `derived_calendar!` one for files with a `base`) included by
+//! `src/markets/<market>/<calendar>.rs`. See `data/README.md` for the format.
+
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use toml::{Table, Value};
+
+const WEEKDAYS: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+const CATEGORIES: [&str; 4] = [
+    "Statutory",
+    "ObservedSubstitute",
+    "SpecialOneOff",
+    "Unscheduled",
+];
+const CONDITIONS: [&str; 4] = ["Always", "Weekday", "Moved", "Sandwiched"];
+
+/// Keys selecting the kind of a rule; exactly one must be present.
+const KINDS: [&str; 10] = [
+    "fixed",
+    "nth",
+    "last",
+    "easter",
+    "orthodox_easter",
+    "on_or_after",
+    "on_or_before",
+    "after",
+    "relative",
+    "one_off",
+];
+const MODIFIERS: [&str; 10] = [
+    "observed",
+    "since",
+    "until",
+    "between",
+    "every",
+    "category",
+    "effective_from",
+    "effective_to",
+    "working_day",
+    "announced",
+];
+
+fn main() {
+    let data = Path::new("data");
+    let out = PathBuf::from(env::var_os("OUT_DIR").unwrap());
+    println!("cargo:rerun-if-changed=data");
+
+    let mut markets: Vec<_> = read_dir(data).into_iter().filter(|p| p.is_dir()).collect();
+    markets.sort();
+    for market in markets {
+        println!("cargo:rerun-if-changed={}", market.display());
+        let mut files: Vec<_> = read_dir(&market)
+            .into_iter()
+            .filter(|p| p.extension().is_some_and(|e| e == "toml"))
+            .collect();
+        files.sort();
+        for file in files {
+            println!("cargo:rerun-if-changed={}", file.display());
+            let code = generate(&file).unwrap_or_else(|e| panic!("{}: {e}", file.display()));
+            let dest = out
+                .join(market.file_name().unwrap())
+                .join(file.with_extension("rs").file_name().unwrap());
+            fs::create_dir_all(dest.parent().unwrap()).unwrap();
+            fs::write(&dest, code).unwrap();
+        }
+    }
+}
+
+fn read_dir(dir: &Path) -> Vec<PathBuf> {
+    fs::read_dir(dir)
+        .unwrap_or_else(|e| panic!("{}: {e}", dir.display()))
+        .map(|entry| entry.unwrap().path())
+        .collect()
+}
+
+fn parse(path: &Path) -> Result<Table, String> {
+    let text = fs::read_to_string(path).map_err(|e| e.to_string())?;
+    text.parse().map_err(|e: toml::de::Error| e.to_string())
+}
+
+/// The `calendar!` or `derived_calendar!` invocation for one data file.
+fn generate(path: &Path) -> Result<String, String> {
+    let doc = parse(path)?;
+    if doc.contains_key("base") {
+        return generate_derived(path, &doc);
+    }
+    check_keys(
+        &doc,
+        &["static", "name", "weekend", "horizon", "rules"],
+        "file",
+    )?;
+
+    let ident = string(&doc, "static")?;
+    let name = string(&doc, "name")?;
+    let mut code = format!(
+        "// @generated by build.rs from {}; do not edit.\n\ncrate::calendar! {{\n    pub static {ident} = {name:?}",
+        path.display()
+    );
+    if let Some(weekend) = doc.get("weekend") {
+        let days = weekend
+            .as_array()
+            .filter(|days| days.len() == 2)
+            .ok_or("`weekend` must be a list of two weekdays")?;
+        let days = days
+            .iter()
+            .map(|d| {
+                d.as_str()
+                    .and_then(weekday)
+                    .ok_or("unknown weekday in `weekend`")
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        write!(code, ", weekend({}, {})", days[0], days[1]).unwrap();
+    }
+    let horizon = doc
+        .get("horizon")
+        .ok_or("missing `horizon`")?
+        .as_array()
+        .filter(|a| a.len() == 2)
+        .and_then(|a| Some((a[0].as_integer()?, a[1].as_integer()?)))
+        .ok_or("`horizon` must be [first, last]")?;
+    if horizon.0 > horizon.1 {
+        return Err("`horizon` must not end before it starts".into());
+    }
+    write!(code, ", horizon({}, {})", horizon.0, horizon.1).unwrap();
+    code.push_str(" {\n");
+
+    let rules = doc
+        .get("rules")
+        .and_then(Value::as_array)
+        .ok_or("missing `[[rules]]`")?;
+    write_rules(&mut code, rules, "        ")?;
+    code.push_str("    }\n}\n");
+    Ok(code)
+}
+
+/// The `derived_calendar!` invocation for a data file deriving from the
+/// calendar of another file of the same market.
+fn generate_derived(path: &Path, doc: &Table) -> Result<String, String> {
+    check_keys(
+        doc,
+        &["static", "name", "base", "without", "replace", "overlay"],
+        "file",
+    )?;
+
+    let ident = string(doc, "static")?;
+    let name = string(doc, "name")?;
+    let base = string(doc, "base")?;
+    let base_path = path.with_file_name(format!("{base}.toml"));
+    let base_doc = parse(&base_path).map_err(|e| format!("`base` {base:?}: {e}"))?;
+    if base_doc.contains_key("base") {
+        return Err(format!("`base` {base:?} is itself derived"));
+    }
+    let base_ident = string(&base_doc, "static").map_err(|e| format!("`base` {base:?}: {e}"))?;
+
+    let mut code = format!(
+        "// @generated by build.rs from {}; do not edit.\n\ncrate::derived_calendar! {{\n    pub static {ident} = {name:?}, base(super::{base}::{base_ident}) {{\n",
+        path.display()
+    );
+    let without = match doc.get("without") {
+        None => Vec::new(),
+        Some(v) => v
+            .as_array()
+            .and_then(|names| names.iter().map(Value::as_str).collect::<Option<Vec<_>>>())
+            .ok_or("`without` must be a list of rule names")?,
+    };
+    let without: Vec<String> = without.iter().map(|n| format!("{n:?}")).collect();
+    writeln!(code, "        without: [{}],", without.join(", ")).unwrap();
+    for section in ["replace", "overlay"] {
+        let rules = match doc.get(section) {
+            None => &[][..],
+            Some(v) => v
+                .as_array()
+                .ok_or_else(|| format!("`{section}` must be `[[{section}]]` tables"))?,
+        };
+        if rules.is_empty() {
+            writeln!(code, "        {section}: {{}},").unwrap();
+        } else {
+            writeln!(code, "        {section}: {{").unwrap();
+            write_rules(&mut code, rules, "            ")?;
+            code.push_str("        },\n");
+        }
+    }
+    code.push_str("    }\n}\n");
+    Ok(code)
+}
+
+/// One `"Name" => expr,` line per rule table.
+fn write_rules(code: &mut String, rules: &[Value], indent: &str) -> Result<(), String> {
+    for (i, rule) in rules.iter().enumerate() {
+        let rule = rule.as_table().ok_or("rules must be tables")?;
+        let name = rule
+            .get("name")
+            .and_then(Value::as_str)
+            .ok_or_else(|| format!("rule #{} has no `name`", i + 1))?;
+        let expr = rule_expr(rule).map_err(|e| format!("rule {name:?}: {e}"))?;
+        writeln!(code, "{indent}{name:?} => {expr},").unwrap();
+    }
+    Ok(())
+}
+
+/// The `dsl` expression for one `[[rules]]` entry.
+fn rule_expr(rule: &Table) -> Result<String, String> {
+    let allowed: Vec<&str> = ["name"].into_iter().chain(KINDS).chain(MODIFIERS).collect();
+    check_keys(rule, &allowed, "rule")?;
+
+    let mut kinds = KINDS.iter().filter(|k| rule.contains_key(**k));
+    let kind = *kinds
+        .next()
+        .ok_or_else(|| format!("needs one of {KINDS:?}"))?;
+    if let Some(other) = kinds.next() {
+        return Err(format!("has both `{kind}` and `{other}`"));
+    }
+
+    let value = &rule[kind];
+    let mut expr = match kind {
+        "fixed" => {
+            let t = table(value, kind, &["month", "day"])?;
+            format!("fixed({}, {})", int(t, "month")?, int(t, "day")?)
+        }
+        "nth" => {
+            let t = table(value, kind, &["month", "weekday", "n"])?;
+            format!(
+                "nth({}, {}, {})",
+                int(t, "month")?,
+                weekday_of(t)?,
+                int(t, "n")?
+            )
+        }
+        "last" => {
+            let t = table(value, kind, &["month", "weekday"])?;
+            format!("last({}, {})", int(t, "month")?, weekday_of(t)?)
+        }
+        "easter" | "orthodox_easter" => {
+            let offset = value
+                .as_integer()
+                .ok_or("Easter offset must be an integer")?;
+            format!("{kind}({offset})")
+        }
+        "on_or_after" | "on_or_before" | "after" => {
+            let t = table(value, kind, &["month", "day", "weekday", "offset"])?;
+            let mut expr = format!(
+                "{kind}({}, {}, {})",
+                int(t, "month")?,
+                int(t, "day")?,
+                weekday_of(t)?
+            );
+            if t.contains_key("offset") {
+                write!(expr, ".shifted({})", int(t, "offset")?).unwrap();
+            }
+            expr
+        }
+        "one_off" => {
+            let (y, m, d) = date(value, kind)?;
+            format!("one_off({y}, {m}, {d})")
+        }
+        "relative" => {
+            let t = table(value, kind, &["base", "offset", "condition", "base_on"])?;
+            let base = t
+                .get("base")
+                .and_then(Value::as_str)
+                .ok_or("`relative.base` must be a rule name")?;
+            let condition = match (t.get("condition"), t.get("base_on")) {
+                (None, None) => "RelativeCondition::Always".to_owned(),
+                (Some(_), Some(_)) => return Err("has both `condition` and `base_on`".into()),
+                (None, Some(day)) => format!(
+                    "RelativeCondition::BaseOn({})",
+                    day.as_str().and_then(weekday).ok_or("unknown weekday")?
+                ),
+                (Some(c), None) => {
+                    let c = c
+                        .as_str()
+                        .filter(|c| CONDITIONS.contains(c))
+                        .ok_or_else(|| format!("`condition` must be one of {CONDITIONS:?}"))?;
+                    format!("RelativeCondition::{c}")
+                }
+            };
+            let offset = t.get("offset").map_or(Ok(0), |o| {
+                o.as_integer().ok_or("`relative.offset` must be an integer")
+            })?;
+            format!("relative({base:?}, {offset}, {condition})")
+        }
+        _ => unreachable!(),
+    };
+
+    if rule
+        .get("observed")
+        .is_some_and(|v| v.as_bool() == Some(true))
+    {
+        expr.push_str(".observed()");
+    }
+    let years: Vec<_> = ["since", "until", "between"]
+        .into_iter()
+        .filter(|k| rule.contains_key(*k))
+        .collect();
+    if years.len() > 1 {
+        return Err(format!("has more than one of {years:?}"));
+    }
+    if let Some(v) = rule.get("since") {
+        write!(
+            expr,
+            ".since({})",
+            v.as_integer().ok_or("`since` must be a year")?
+        )
+        .unwrap();
+    }
+    if let Some(v) = rule.get("until") {
+        write!(
+            expr,
+            ".until({})",
+            v.as_integer().ok_or("`until` must be a year")?
+        )
+        .unwrap();
+    }
+    if let Some(v) = rule.get("between") {
+        let years = v
+            .as_array()
+            .filter(|a| a.len() == 2)
+            .and_then(|a| Some((a[0].as_integer()?, a[1].as_integer()?)))
+            .ok_or("`between` must be [first, last]")?;
+        write!(expr, ".between({}, {})", years.0, years.1).unwrap();
+    }
+    if let Some(v) = rule.get("every") {
+        let t = table(v, "every", &["modulus", "remainder"])?;
+        let modulus = int(t, "modulus")?;
+        if modulus <= 0 {
+            return Err("`every.modulus` must be positive".into());
+        }
+        write!(
+            expr,
+            ".every(YearCycle::new({modulus}, {}))",
+            int(t, "remainder")?
+        )
+        .unwrap();
+    }
+    if let Some(v) = rule.get("category") {
+        let c = v
+            .as_str()
+            .filter(|c| CATEGORIES.contains(c))
+            .ok_or_else(|| format!("`category` must be one of {CATEGORIES:?}"))?;
+        write!(expr, ".with_category({c})").unwrap();
+    }
+    for (key, method) in [
+        ("effective_from", "effective_from"),
+        ("effective_to", "effective_to"),
+        ("announced", "announced_on"),
+    ] {
+        if let Some(v) = rule.get(key) {
+            let (y, m, d) = date(v, key)?;
+            write!(expr, ".{method}(Date::ymd({y}, {m}, {d}))").unwrap();
+        }
+    }
+    if rule
+        .get("working_day")
+        .is_some_and(|v| v.as_bool() == Some(true))
+    {
+        expr.push_str(".with_effect(RuleEffect::WorkingDay)");
+    }
+    Ok(expr)
+}
+
+fn check_keys(table: &Table, allowed: &[&str], what: &str) -> Result<(), String> {
+    match table.keys().find(|k| !allowed.contains(&k.as_str())) {
+        Some(key) => Err(format!("unknown {what} key `{key}`")),
+        None => Ok(()),
+    }
+}
+
+fn string(table: &Table, key: &str) -> Result<String, String> {
+    table
+        .get(key)
+        .and_then(Value::as_str)
+        .map(str::to_owned)
+        .ok_or_else(|| format!("missing string `{key}`"))
+}
+
+fn table<'a>(value: &'a Value, key: &str, allowed: &[&str]) -> Result<&'a Table, String> {
+    let t = value
+        .as_table()
+        .ok_or_else(|| format!("`{key}` must be a table"))?;
+    check_keys(t, allowed, key)?;
+    Ok(t)
+}
+
+fn int(table: &Table, key: &str) -> Result<i64, String> {
+    table
+        .get(key)
+        .and_then(Value::as_integer)
+        .ok_or_else(|| format!("missing integer `{key}`"))
+}
+
+fn weekday(name: &str) -> Option<&str> {
+    WEEKDAYS.iter().copied().find(|d| *d == name)
+}
+
+fn weekday_of(table: &Table) -> Result<&str, String> {
+    table
+        .get("weekday")
+        .and_then(Value::as_str)
+        .and_then(weekday)
+        .ok_or_else(|| format!("`weekday` must be one of {WEEKDAYS:?}"))
+}
+
+fn date(value: &Value, key: &str) -> Result<(u16, u8, u8), String> {
+    value
+        .as_datetime()
+        .and_then(|dt| dt.date.filter(|_| dt.time.is_none() && dt.offset.is_none()))
+        .map(|d| (d.year, d.month, d.day))
+        .ok_or_else(|| format!("`{key}` must be a date (YYYY-MM-DD)"))
+}
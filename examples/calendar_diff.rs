@@ -0,0 +1,76 @@
+//! Compare two market calendars over a range of years.
+//!
+//! ```bash
+//! cargo run --example calendar_diff -- us-sofr us-nyse 2000 2025
+//! ```
+
+use std::env;
+use std::process;
+
+use business_calendar::diff::{diff, Side};
+use business_calendar::markets::fr::{FRMarket, France};
+use business_calendar::markets::uk::{UKMarket, UnitedKingdom};
+use business_calendar::markets::us::{USMarket, UnitedStates};
+use business_calendar::{Calendar, Date, DateRange};
+
+fn calendar(name: &str) -> Option<Box<dyn Calendar>> {
+    let cal: Box<dyn Calendar> = match name {
+        "us-settlement" => Box::new(UnitedStates::new(USMarket::Settlement)),
+        "us-libor" => Box::new(UnitedStates::new(USMarket::LiborImpact)),
+        "us-nyse" => Box::new(UnitedStates::new(USMarket::NYSE)),
+        "us-govbond" => Box::new(UnitedStates::new(USMarket::GovernmentBond)),
+        "us-sofr" => Box::new(UnitedStates::new(USMarket::SOFR)),
+        "us-nerc" => Box::new(UnitedStates::new(USMarket::NERC)),
+        "us-fed" => Box::new(UnitedStates::new(USMarket::FederalReserve)),
+        "uk-settlement" => Box::new(UnitedKingdom::new(UKMarket::Settlement)),
+        "uk-exchange" => Box::new(UnitedKingdom::new(UKMarket::Exchange)),
+        "uk-metals" => Box::new(UnitedKingdom::new(UKMarket::Metals)),
+        "fr-settlement" => Box::new(France::new(FRMarket::Settlement)),
+        "fr-exchange" => Box::new(France::new(FRMarket::Exchange)),
+        _ => return None,
+    };
+    Some(cal)
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if args.len() != 4 {
+        eprintln!("usage: calendar_diff <left> <right> <from-year> <to-year>");
+        process::exit(2);
+    }
+
+    let (Some(left), Some(right)) = (calendar(&args[0]), calendar(&args[1])) else {
+        eprintln!("unknown calendar; expected e.g. us-nyse, uk-exchange, fr-settlement");
+        process::exit(2);
+    };
+    let (Ok(from), Ok(to)) = (args[2].parse::<i32>(), args[3].parse::<i32>()) else {
+        eprintln!("years must be integers");
+        process::exit(2);
+    };
+
+    let range = DateRange::inclusive(Date::ymd(from, 1, 1), Date::ymd(to, 12, 31));
+    let differences = diff(left.as_ref(), right.as_ref(), range);
+
+    for d in &differences {
+        let (open, closed) = match d.business_day_in {
+            Side::Left => (&args[0], &args[1]),
+            Side::Right => (&args[1], &args[0]),
+        };
+        let reason = if d.rules.is_empty() {
+            "no matching rule".to_string()
+        } else {
+            d.rules
+                .iter()
+                .map(|r| r.name)
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        println!(
+            "{}-{:02}-{:02}  open in {open}, closed in {closed} ({reason})",
+            d.date.year(),
+            d.date.month(),
+            d.date.day(),
+        );
+    }
+    println!("{} difference(s)", differences.len());
+}
@@ -1,9 +1,115 @@
+use std::ops::Bound;
+use std::sync::Arc;
+
 use chrono::Weekday;
 
+use crate::category::{CategoryFilter, HolidayCategories, HolidayCategory};
+use crate::currency::Currency;
 use crate::date::Date;
+use crate::date_range::{BusinessDays, DateRange, Holidays};
+use crate::explain::Explanation;
+use crate::horizon::{self, Answer, Horizon, OutOfRange, OutOfRangePolicy};
+use crate::period::{Period, TimeUnit};
+use crate::rules::{NamedRule, Occurrence, YearRange};
+
+/// How many years [`Calendar::next_holiday`] and [`Calendar::previous_holiday`]
+/// search before giving up.
+pub const HOLIDAY_SEARCH_YEARS: i32 = 100;
+
+/// Name reported by calendars that do not override [`Calendar::name`].
+pub const UNNAMED_CALENDAR: &str = "Unnamed calendar";
+
+/// Name reported for holidays that no rule accounts for.
+pub const UNNAMED_HOLIDAY: &str = "Holiday";
+
+/// Business-day conventions used to roll a non-business day onto a business day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BusinessDayConvention {
+    /// Leave the date as is, even if it is not a business day.
+    Unadjusted,
+    /// First business day after the date.
+    Following,
+    /// First business day after the date, unless it falls in the next month;
+    /// then the first business day before.
+    ModifiedFollowing,
+    /// First business day before the date.
+    Preceding,
+    /// First business day before the date, unless it falls in the previous
+    /// month; then the first business day after.
+    ModifiedPreceding,
+    /// Nearest business day, preferring the following one on ties.
+    Nearest,
+}
+
+/// Simple calendar trait – business-day queries plus date adjustment.
+///
+/// Calendars are `Send + Sync` so they can be shared across threads, and the
+/// trait is object safe: `&dyn Calendar`, `Box<dyn Calendar>` and
+/// `Arc<dyn Calendar>` are all calendars too.
+pub trait Calendar: Send + Sync {
+    /// Human-readable name, e.g. `"US NYSE"`.
+    fn name(&self) -> &str {
+        UNNAMED_CALENDAR
+    }
+
+    /// Stable identifier suitable for configs, logs and audit trails, e.g.
+    /// `"US-NYSE"`. Defaults to [`Calendar::name`].
+    fn code(&self) -> &str {
+        self.name()
+    }
+
+    /// ISO 3166-1 alpha-2 code of the country this calendar belongs to.
+    fn country_code(&self) -> Option<&str> {
+        None
+    }
+
+    /// ISO 10383 market identifier code of the exchange this calendar
+    /// follows, if it follows one.
+    fn mic(&self) -> Option<&str> {
+        None
+    }
+
+    /// Currency settled on this calendar's business days. Defaults to that
+    /// of its [`Calendar::country_code`]; markets settling in another
+    /// currency override it.
+    ///
+    /// ```rust
+    /// use business_calendar::fx::Currency;
+    /// use business_calendar::markets::uk::{UKMarket, UnitedKingdom};
+    /// use business_calendar::Calendar;
+    ///
+    /// let exchange = UnitedKingdom::new(UKMarket::Exchange);
+    /// assert_eq!(exchange.settlement_currency(), Some(Currency::GBP));
+    /// // The LME settles in dollars.
+    /// let lme = UnitedKingdom::new(UKMarket::Metals);
+    /// assert_eq!(lme.settlement_currency(), Some(Currency::USD));
+    /// ```
+    fn settlement_currency(&self) -> Option<Currency> {
+        self.country_code().and_then(Currency::of_country)
+    }
+
+    /// IANA time zone name of the market, e.g. `"America/New_York"`.
+    fn time_zone(&self) -> Option<&str> {
+        None
+    }
+
+    /// Years over which this calendar's answers are reliable: its rules can
+    /// be evaluated and its list of closures is believed complete.
+    ///
+    /// Use [`Calendar::with_horizon`] to narrow it, and the `try_` methods
+    /// (e.g. [`Calendar::try_is_business_day`]) to learn when a query goes
+    /// beyond it.
+    fn supported_years(&self) -> YearRange {
+        YearRange::always()
+    }
+
+    /// What the `try_` methods do with dates outside
+    /// [`Calendar::supported_years`]; [`OutOfRangePolicy::Error`] unless
+    /// overridden.
+    fn out_of_range_policy(&self) -> OutOfRangePolicy {
+        OutOfRangePolicy::Error
+    }
 
-/// Simple calendar trait – can be extended with adjust/advance later.
-pub trait Calendar {
     /// Returns `true` if the given date is a business day in this calendar.
     fn is_business_day(&self, date: Date) -> bool;
 
@@ -13,9 +119,543 @@ pub trait Calendar {
         matches!(date.weekday(), Weekday::Sat | Weekday::Sun)
     }
 
+    /// Returns `true` if every date falling on `weekday` is closed in this
+    /// calendar, so iterators may skip such dates without asking.
+    ///
+    /// Defaults to `false`, which is always correct; calendars whose weekend
+    /// can be overridden on specific dates (make-up working days) must keep
+    /// it `false` for those weekdays.
+    #[inline]
+    fn is_always_closed_on(&self, _weekday: Weekday) -> bool {
+        false
+    }
+
     /// Returns `true` if the given date is a holiday (non-weekend non-business-day).
     #[inline]
     fn is_holiday(&self, date: Date) -> bool {
         !self.is_business_day(date) && !self.is_weekend(date)
     }
+
+    /// Holiday rules of this calendar that match `date`.
+    ///
+    /// Empty for calendars that are not built from rules.
+    fn matching_rules(&self, _date: Date) -> Vec<NamedRule> {
+        Vec::new()
+    }
+
+    /// Holidays of the rules matching `date`, with their actual and observed
+    /// dates.
+    fn matching_occurrences(&self, date: Date) -> Vec<Occurrence> {
+        self.matching_rules(date)
+            .iter()
+            .filter_map(|rule| rule.occurrence(date.year()))
+            .collect()
+    }
+
+    /// Explains why `date` is or is not a business day: weekend, matching
+    /// holiday rules (with actual and observed dates) and market overrides.
+    fn explain(&self, date: Date) -> Explanation {
+        Explanation {
+            date,
+            business_day: self.is_business_day(date),
+            weekend: self.is_weekend(date),
+            holidays: self.matching_occurrences(date),
+            overrides: Vec::new(),
+        }
+    }
+
+    /// First business day strictly after `date`.
+    fn next_business_day(&self, date: Date) -> Date {
+        let mut d = date.add_days(1);
+        while !self.is_business_day(d) {
+            d = d.add_days(1);
+        }
+        d
+    }
+
+    /// Last business day strictly before `date`.
+    fn previous_business_day(&self, date: Date) -> Date {
+        let mut d = date.add_days(-1);
+        while !self.is_business_day(d) {
+            d = d.add_days(-1);
+        }
+        d
+    }
+
+    /// First holiday strictly after `date`, with its name.
+    ///
+    /// The default implementation scans day by day for up to
+    /// [`HOLIDAY_SEARCH_YEARS`] years; rule-based calendars derive the answer
+    /// from their rules instead.
+    fn next_holiday(&self, date: Date) -> Option<Occurrence> {
+        let end = Date::ymd(date.year() + HOLIDAY_SEARCH_YEARS, 12, 31);
+        let range = DateRange::new((Bound::Excluded(date), Bound::Included(end)));
+        let d = Holidays::new(self, range).next()?;
+        Some(holiday_occurrence(self, d))
+    }
+
+    /// Last holiday strictly before `date`, with its name.
+    ///
+    /// See [`Calendar::next_holiday`] for the search horizon.
+    fn previous_holiday(&self, date: Date) -> Option<Occurrence> {
+        let start = Date::ymd(date.year() - HOLIDAY_SEARCH_YEARS, 1, 1);
+        let d = Holidays::new(self, DateRange::exclusive(start, date)).next_back()?;
+        Some(holiday_occurrence(self, d))
+    }
+
+    /// Rolls `date` onto a business day according to `convention`.
+    ///
+    /// Business days are returned unchanged.
+    fn adjust(&self, date: Date, convention: BusinessDayConvention) -> Date {
+        let roll = |step: i32| {
+            let mut d = date;
+            while !self.is_business_day(d) {
+                d = d.add_days(step);
+            }
+            d
+        };
+
+        match convention {
+            BusinessDayConvention::Unadjusted => date,
+            BusinessDayConvention::Following => roll(1),
+            BusinessDayConvention::Preceding => roll(-1),
+            BusinessDayConvention::ModifiedFollowing => {
+                let d = roll(1);
+                if d.month() != date.month() {
+                    roll(-1)
+                } else {
+                    d
+                }
+            }
+            BusinessDayConvention::ModifiedPreceding => {
+                let d = roll(-1);
+                if d.month() != date.month() {
+                    roll(1)
+                } else {
+                    d
+                }
+            }
+            BusinessDayConvention::Nearest => {
+                let mut offset = 0;
+                loop {
+                    if self.is_business_day(date.add_days(offset)) {
+                        return date.add_days(offset);
+                    }
+                    if self.is_business_day(date.add_days(-offset)) {
+                        return date.add_days(-offset);
+                    }
+                    offset += 1;
+                }
+            }
+        }
+    }
+
+    /// Advances `date` by `period`.
+    ///
+    /// Day periods count business days (zero days just adjusts `date`); other
+    /// units add calendar time and then adjust with `convention`. With
+    /// `end_of_month`, a start date on the last business day of its month
+    /// maps to the last business day of the target month.
+    fn advance_by(
+        &self,
+        date: Date,
+        period: Period,
+        convention: BusinessDayConvention,
+        end_of_month: bool,
+    ) -> Date {
+        match period.unit {
+            TimeUnit::Days => {
+                if period.length == 0 {
+                    return self.adjust(date, convention);
+                }
+                let step = period.length.signum();
+                let mut d = date;
+                for _ in 0..period.length.abs() {
+                    d = d.add_days(step);
+                    while !self.is_business_day(d) {
+                        d = d.add_days(step);
+                    }
+                }
+                d
+            }
+            TimeUnit::Weeks => self.adjust(date + period, convention),
+            TimeUnit::Months | TimeUnit::Years => {
+                let target = date + period;
+                if end_of_month && self.is_end_of_month(date) {
+                    return self.end_of_month(target);
+                }
+                self.adjust(target, convention)
+            }
+        }
+    }
+
+    /// [`Calendar::is_business_day`], surfacing dates outside
+    /// [`Calendar::supported_years`] according to
+    /// [`Calendar::out_of_range_policy`].
+    ///
+    /// ```rust
+    /// use business_calendar::markets::uk::UK_SETTLEMENT;
+    /// use business_calendar::{Answer, Calendar, Date};
+    ///
+    /// // Easter tables stop at 2199.
+    /// assert_eq!(UK_SETTLEMENT.try_is_business_day(Date::ymd(2025, 4, 18)), Ok(Answer::Known(false)));
+    /// assert!(UK_SETTLEMENT.try_is_business_day(Date::ymd(2200, 4, 18)).is_err());
+    /// ```
+    fn try_is_business_day(&self, date: Date) -> Result<Answer<bool>, OutOfRange> {
+        Ok(horizon::check(self, date)?.map(|()| self.is_business_day(date)))
+    }
+
+    /// [`Calendar::is_holiday`], surfacing dates outside the supported years;
+    /// see [`Calendar::try_is_business_day`].
+    fn try_is_holiday(&self, date: Date) -> Result<Answer<bool>, OutOfRange> {
+        Ok(horizon::check(self, date)?.map(|()| self.is_holiday(date)))
+    }
+
+    /// [`Calendar::adjust`], surfacing a `date` or result outside the
+    /// supported years; see [`Calendar::try_is_business_day`].
+    fn try_adjust(
+        &self,
+        date: Date,
+        convention: BusinessDayConvention,
+    ) -> Result<Answer<Date>, OutOfRange> {
+        let start = horizon::check(self, date)?;
+        let adjusted = self.adjust(date, convention);
+        let end = horizon::check(self, adjusted)?;
+        Ok(start.and(end).map(|()| adjusted))
+    }
+
+    /// [`Calendar::advance_by`], surfacing a `date` or result outside the
+    /// supported years; see [`Calendar::try_is_business_day`].
+    fn try_advance_by(
+        &self,
+        date: Date,
+        period: Period,
+        convention: BusinessDayConvention,
+        end_of_month: bool,
+    ) -> Result<Answer<Date>, OutOfRange> {
+        let start = horizon::check(self, date)?;
+        let advanced = self.advance_by(date, period, convention, end_of_month);
+        let end = horizon::check(self, advanced)?;
+        Ok(start.and(end).map(|()| advanced))
+    }
+
+    /// Lazily iterates over the business days in `range`, in either direction.
+    #[inline]
+    fn business_days(&self, range: DateRange) -> BusinessDays<'_, Self>
+    where
+        Self: Sized,
+    {
+        BusinessDays::new(self, range)
+    }
+
+    /// Lazily iterates over the holidays (non-weekend non-business days) in
+    /// `range`, in either direction.
+    #[inline]
+    fn holidays(&self, range: DateRange) -> Holidays<'_, Self>
+    where
+        Self: Sized,
+    {
+        Holidays::new(self, range)
+    }
+
+    /// View of this calendar that only honours holiday rules in `categories`,
+    /// e.g. [`HolidayCategories::SCHEDULED`] to ignore unscheduled closures.
+    #[inline]
+    fn with_categories(&self, categories: HolidayCategories) -> CategoryFilter<'_, Self>
+    where
+        Self: Sized,
+    {
+        CategoryFilter::new(self, categories)
+    }
+
+    /// View of this calendar trusted only within `years`, handling queries
+    /// beyond them according to `policy`.
+    #[inline]
+    fn with_horizon(&self, years: YearRange, policy: OutOfRangePolicy) -> Horizon<'_, Self>
+    where
+        Self: Sized,
+    {
+        Horizon::new(self, years, policy)
+    }
+
+    /// First business day of the month containing `date`.
+    #[inline]
+    fn start_of_month(&self, date: Date) -> Date {
+        let first = Date::ymd(date.year(), date.month(), 1);
+        self.adjust(first, BusinessDayConvention::Following)
+    }
+
+    /// Last business day of the month containing `date`.
+    #[inline]
+    fn end_of_month(&self, date: Date) -> Date {
+        let last = Date::ymd(date.year(), date.month(), date.days_in_month());
+        self.adjust(last, BusinessDayConvention::Preceding)
+    }
+
+    /// Returns `true` if `date` is the last business day of its month.
+    #[inline]
+    fn is_end_of_month(&self, date: Date) -> bool {
+        self.is_business_day(date) && self.end_of_month(date) == date
+    }
+
+    /// The `n`th business day within `period` (`n > 0`: from the start;
+    /// `n < 0`: from the end, `-1` being the last). Returns `None` if
+    /// `n == 0` or `period` has fewer than `|n|` business days.
+    ///
+    /// Combine with [`DateRange::week_of`], [`DateRange::month_of`],
+    /// [`DateRange::quarter_of`] or [`DateRange::year_of`], e.g. the third
+    /// business day of the month is `nth_business_day_of(DateRange::month_of(d), 3)`.
+    fn nth_business_day_of(&self, period: DateRange, n: i32) -> Option<Date> {
+        let mut days = BusinessDays::new(self, period);
+        match n {
+            0 => None,
+            n if n > 0 => days.nth(n as usize - 1),
+            n => days.rev().nth((-n) as usize - 1),
+        }
+    }
+}
+
+/// Implements [`Calendar`] methods by forwarding them to `$inner`, an
+/// expression of `$self` evaluating to another calendar.
+///
+/// Methods are forwarded in groups, so that wrappers overriding some of them
+/// still pick up trait methods added later:
+///
+/// - `name`: [`Calendar::name`];
+/// - `metadata`: code, country code, MIC, settlement currency and time zone;
+/// - `horizon`: supported years and out-of-range policy;
+/// - `weekend`: [`Calendar::is_weekend`] and [`Calendar::is_always_closed_on`];
+/// - `rules`: business days, holidays, matching rules and explanations;
+/// - `navigation`: moving between business days and months;
+/// - `fallible`: the `try_` methods;
+/// - `all`: every group.
+///
+/// Groups left out fall back to the trait's default methods, which go
+/// through the wrapper's own overrides.
+macro_rules! forward_calendar {
+    ($self:ident => $inner:expr; $($group:ident),+ $(,)?) => {
+        $($crate::calendar::forward_calendar!(@$group $self => $inner);)+
+    };
+    (@all $self:ident => $inner:expr) => {
+        $crate::calendar::forward_calendar!(
+            $self => $inner; name, metadata, horizon, weekend, rules, navigation, fallible
+        );
+    };
+    (@name $self:ident => $inner:expr) => {
+        #[inline]
+        fn name(&$self) -> &str {
+            $inner.name()
+        }
+    };
+    (@metadata $self:ident => $inner:expr) => {
+        #[inline]
+        fn code(&$self) -> &str {
+            $inner.code()
+        }
+
+        #[inline]
+        fn country_code(&$self) -> Option<&str> {
+            $inner.country_code()
+        }
+
+        #[inline]
+        fn mic(&$self) -> Option<&str> {
+            $inner.mic()
+        }
+
+        #[inline]
+        fn settlement_currency(&$self) -> Option<$crate::fx::Currency> {
+            $inner.settlement_currency()
+        }
+
+        #[inline]
+        fn time_zone(&$self) -> Option<&str> {
+            $inner.time_zone()
+        }
+    };
+    (@horizon $self:ident => $inner:expr) => {
+        #[inline]
+        fn supported_years(&$self) -> $crate::rules::YearRange {
+            $inner.supported_years()
+        }
+
+        #[inline]
+        fn out_of_range_policy(&$self) -> $crate::horizon::OutOfRangePolicy {
+            $inner.out_of_range_policy()
+        }
+    };
+    (@weekend $self:ident => $inner:expr) => {
+        #[inline]
+        fn is_weekend(&$self, date: $crate::date::Date) -> bool {
+            $inner.is_weekend(date)
+        }
+
+        #[inline]
+        fn is_always_closed_on(&$self, weekday: ::chrono::Weekday) -> bool {
+            $inner.is_always_closed_on(weekday)
+        }
+    };
+    (@rules $self:ident => $inner:expr) => {
+        #[inline]
+        fn is_business_day(&$self, date: $crate::date::Date) -> bool {
+            $inner.is_business_day(date)
+        }
+
+        #[inline]
+        fn is_holiday(&$self, date: $crate::date::Date) -> bool {
+            $inner.is_holiday(date)
+        }
+
+        #[inline]
+        fn matching_rules(&$self, date: $crate::date::Date) -> Vec<$crate::rules::NamedRule> {
+            $inner.matching_rules(date)
+        }
+
+        #[inline]
+        fn matching_occurrences(
+            &$self,
+            date: $crate::date::Date,
+        ) -> Vec<$crate::rules::Occurrence> {
+            $inner.matching_occurrences(date)
+        }
+
+        #[inline]
+        fn explain(&$self, date: $crate::date::Date) -> $crate::explain::Explanation {
+            $inner.explain(date)
+        }
+
+        #[inline]
+        fn next_holiday(&$self, date: $crate::date::Date) -> Option<$crate::rules::Occurrence> {
+            $inner.next_holiday(date)
+        }
+
+        #[inline]
+        fn previous_holiday(
+            &$self,
+            date: $crate::date::Date,
+        ) -> Option<$crate::rules::Occurrence> {
+            $inner.previous_holiday(date)
+        }
+    };
+    (@navigation $self:ident => $inner:expr) => {
+        #[inline]
+        fn next_business_day(&$self, date: $crate::date::Date) -> $crate::date::Date {
+            $inner.next_business_day(date)
+        }
+
+        #[inline]
+        fn previous_business_day(&$self, date: $crate::date::Date) -> $crate::date::Date {
+            $inner.previous_business_day(date)
+        }
+
+        #[inline]
+        fn adjust(
+            &$self,
+            date: $crate::date::Date,
+            convention: $crate::calendar::BusinessDayConvention,
+        ) -> $crate::date::Date {
+            $inner.adjust(date, convention)
+        }
+
+        #[inline]
+        fn advance_by(
+            &$self,
+            date: $crate::date::Date,
+            period: $crate::period::Period,
+            convention: $crate::calendar::BusinessDayConvention,
+            end_of_month: bool,
+        ) -> $crate::date::Date {
+            $inner.advance_by(date, period, convention, end_of_month)
+        }
+
+        #[inline]
+        fn start_of_month(&$self, date: $crate::date::Date) -> $crate::date::Date {
+            $inner.start_of_month(date)
+        }
+
+        #[inline]
+        fn end_of_month(&$self, date: $crate::date::Date) -> $crate::date::Date {
+            $inner.end_of_month(date)
+        }
+
+        #[inline]
+        fn is_end_of_month(&$self, date: $crate::date::Date) -> bool {
+            $inner.is_end_of_month(date)
+        }
+
+        #[inline]
+        fn nth_business_day_of(
+            &$self,
+            period: $crate::date_range::DateRange,
+            n: i32,
+        ) -> Option<$crate::date::Date> {
+            $inner.nth_business_day_of(period, n)
+        }
+    };
+    (@fallible $self:ident => $inner:expr) => {
+        #[inline]
+        fn try_is_business_day(
+            &$self,
+            date: $crate::date::Date,
+        ) -> Result<$crate::horizon::Answer<bool>, $crate::horizon::OutOfRange> {
+            $inner.try_is_business_day(date)
+        }
+
+        #[inline]
+        fn try_is_holiday(
+            &$self,
+            date: $crate::date::Date,
+        ) -> Result<$crate::horizon::Answer<bool>, $crate::horizon::OutOfRange> {
+            $inner.try_is_holiday(date)
+        }
+
+        #[inline]
+        fn try_adjust(
+            &$self,
+            date: $crate::date::Date,
+            convention: $crate::calendar::BusinessDayConvention,
+        ) -> Result<$crate::horizon::Answer<$crate::date::Date>, $crate::horizon::OutOfRange> {
+            $inner.try_adjust(date, convention)
+        }
+
+        #[inline]
+        fn try_advance_by(
+            &$self,
+            date: $crate::date::Date,
+            period: $crate::period::Period,
+            convention: $crate::calendar::BusinessDayConvention,
+            end_of_month: bool,
+        ) -> Result<$crate::horizon::Answer<$crate::date::Date>, $crate::horizon::OutOfRange> {
+            $inner.try_advance_by(date, period, convention, end_of_month)
+        }
+    };
+}
+
+pub(crate) use forward_calendar;
+
+impl<C: Calendar + ?Sized> Calendar for &C {
+    forward_calendar!(self => (**self); all);
+}
+
+impl<C: Calendar + ?Sized> Calendar for Box<C> {
+    forward_calendar!(self => (**self); all);
+}
+
+impl<C: Calendar + ?Sized> Calendar for Arc<C> {
+    forward_calendar!(self => (**self); all);
+}
+
+/// The holiday observed on `date`, named after the first matching rule of
+/// `calendar` (or [`UNNAMED_HOLIDAY`] if no rule matches).
+pub(crate) fn holiday_occurrence<C: Calendar + ?Sized>(calendar: &C, date: Date) -> Occurrence {
+    calendar
+        .matching_occurrences(date)
+        .into_iter()
+        .find(|occ| occ.observed == date)
+        .unwrap_or(Occurrence {
+            name: UNNAMED_HOLIDAY,
+            category: HolidayCategory::Statutory,
+            actual: date,
+            observed: date,
+        })
 }
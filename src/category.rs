@@ -0,0 +1,183 @@
+//! Holiday categories and calendar views restricted to some of them.
+
+use crate::calendar::{forward_calendar, holiday_occurrence, Calendar};
+use crate::date::Date;
+use crate::explain::Explanation;
+use crate::rules::{NamedRule, Occurrence};
+
+/// Kind of closure a holiday rule describes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HolidayCategory {
+    /// Recurring holiday set by law or market rules (including its usual
+    /// weekend observance).
+    Statutory,
+    /// A regular holiday moved to another date in a given year (e.g. the UK
+    /// Spring Bank Holiday moved for a jubilee).
+    ObservedSubstitute,
+    /// Planned one-off closure announced in advance (jubilees, royal
+    /// weddings, national days of mourning).
+    SpecialOneOff,
+    /// Unscheduled or emergency closure (weather, market emergencies).
+    Unscheduled,
+}
+
+impl HolidayCategory {
+    #[inline]
+    const fn bit(self) -> u8 {
+        1 << self as u8
+    }
+}
+
+/// A set of [`HolidayCategory`] values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HolidayCategories(u8);
+
+impl HolidayCategories {
+    /// No categories.
+    pub const NONE: Self = Self(0);
+
+    /// Every category.
+    pub const ALL: Self = Self::NONE
+        .with(HolidayCategory::Statutory)
+        .with(HolidayCategory::ObservedSubstitute)
+        .with(HolidayCategory::SpecialOneOff)
+        .with(HolidayCategory::Unscheduled);
+
+    /// Every category except [`HolidayCategory::Unscheduled`]: the closures
+    /// that were known in advance, suitable for forward-looking projections.
+    pub const SCHEDULED: Self = Self::ALL.without(HolidayCategory::Unscheduled);
+
+    /// This set plus `category`.
+    #[inline]
+    pub const fn with(self, category: HolidayCategory) -> Self {
+        Self(self.0 | category.bit())
+    }
+
+    /// This set minus `category`.
+    #[inline]
+    pub const fn without(self, category: HolidayCategory) -> Self {
+        Self(self.0 & !category.bit())
+    }
+
+    /// Returns `true` if `category` is in the set.
+    #[inline]
+    pub const fn contains(self, category: HolidayCategory) -> bool {
+        self.0 & category.bit() != 0
+    }
+}
+
+impl From<HolidayCategory> for HolidayCategories {
+    #[inline]
+    fn from(category: HolidayCategory) -> Self {
+        Self::NONE.with(category)
+    }
+}
+
+/// View of a calendar that only honours holiday rules in some categories.
+///
+/// A date closed by the underlying calendar reopens when every rule closing
+/// it is in an excluded category. Weekends, and closures not explained by
+/// any rule, are kept.
+///
+/// Created by [`Calendar::with_categories`].
+///
+/// ```rust
+/// use business_calendar::markets::us::{USMarket, UnitedStates};
+/// use business_calendar::{Calendar, Date, HolidayCategories};
+///
+/// let nyse = UnitedStates::new(USMarket::NYSE);
+/// let sandy = Date::ymd(2012, 10, 29);
+/// assert!(!nyse.is_business_day(sandy));
+/// assert!(nyse.with_categories(HolidayCategories::SCHEDULED).is_business_day(sandy));
+/// ```
+#[derive(Debug)]
+pub struct CategoryFilter<'a, C: ?Sized> {
+    calendar: &'a C,
+    categories: HolidayCategories,
+}
+
+impl<C: ?Sized> Clone for CategoryFilter<'_, C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C: ?Sized> Copy for CategoryFilter<'_, C> {}
+
+impl<'a, C: Calendar + ?Sized> CategoryFilter<'a, C> {
+    /// `calendar` restricted to holiday rules in `categories`.
+    #[inline]
+    pub fn new(calendar: &'a C, categories: HolidayCategories) -> Self {
+        Self {
+            calendar,
+            categories,
+        }
+    }
+
+    /// Categories this view honours.
+    #[inline]
+    pub fn categories(&self) -> HolidayCategories {
+        self.categories
+    }
+}
+
+impl<C: Calendar + ?Sized> Calendar for CategoryFilter<'_, C> {
+    forward_calendar!(self => self.calendar; name, metadata, horizon, weekend);
+
+    fn is_business_day(&self, date: Date) -> bool {
+        if self.calendar.is_business_day(date) {
+            return true;
+        }
+        if self.calendar.is_weekend(date) {
+            return false;
+        }
+        let rules = self.calendar.matching_rules(date);
+        !rules.is_empty()
+            && rules
+                .iter()
+                .all(|rule| !self.categories.contains(rule.category))
+    }
+
+    fn matching_rules(&self, date: Date) -> Vec<NamedRule> {
+        let mut rules = self.calendar.matching_rules(date);
+        rules.retain(|rule| self.categories.contains(rule.category));
+        rules
+    }
+
+    fn matching_occurrences(&self, date: Date) -> Vec<Occurrence> {
+        let mut occurrences = self.calendar.matching_occurrences(date);
+        occurrences.retain(|occ| self.categories.contains(occ.category));
+        occurrences
+    }
+
+    fn explain(&self, date: Date) -> Explanation {
+        let mut explanation = self.calendar.explain(date);
+        explanation.business_day = self.is_business_day(date);
+        explanation
+            .holidays
+            .retain(|occ| self.categories.contains(occ.category));
+        explanation
+    }
+
+    fn next_holiday(&self, date: Date) -> Option<Occurrence> {
+        let mut from = date;
+        loop {
+            let occ = self.calendar.next_holiday(from)?;
+            if self.is_holiday(occ.observed) {
+                return Some(holiday_occurrence(self, occ.observed));
+            }
+            from = occ.observed;
+        }
+    }
+
+    fn previous_holiday(&self, date: Date) -> Option<Occurrence> {
+        let mut from = date;
+        loop {
+            let occ = self.calendar.previous_holiday(from)?;
+            if self.is_holiday(occ.observed) {
+                return Some(holiday_occurrence(self, occ.observed));
+            }
+            from = occ.observed;
+        }
+    }
+}
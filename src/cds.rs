@@ -0,0 +1,63 @@
+//! CDS standard dates: quarterly roll dates on the 20th of March, June,
+//! September and December, with the 2015 semi-annual roll convention for
+//! standard maturities.
+
+use crate::calendar::{BusinessDayConvention, Calendar};
+use crate::date::Date;
+
+#[inline]
+fn is_cds_month(month: u32) -> bool {
+    month.is_multiple_of(3)
+}
+
+/// Returns `true` if `date` is a (quarterly) CDS date: the 20th of March,
+/// June, September or December.
+pub fn is_cds_date(date: Date) -> bool {
+    date.day() == 20 && is_cds_month(date.month())
+}
+
+/// First CDS date strictly after `date`.
+pub fn next_cds_date(date: Date) -> Date {
+    let mut candidate = Date::ymd(date.year(), date.month(), 20);
+    while candidate <= date || !is_cds_month(candidate.month()) {
+        candidate = candidate.add_months(1);
+    }
+    candidate
+}
+
+/// Last CDS date strictly before `date`.
+pub fn previous_cds_date(date: Date) -> Date {
+    let mut candidate = Date::ymd(date.year(), date.month(), 20);
+    while candidate >= date || !is_cds_month(candidate.month()) {
+        candidate = candidate.add_months(-1);
+    }
+    candidate
+}
+
+/// First CDS date strictly after `date`, rolled to a business day of
+/// `calendar` with the Following convention (as for CDS payment dates).
+pub fn next_cds_date_adjusted<C: Calendar + ?Sized>(date: Date, calendar: &C) -> Date {
+    calendar.adjust(next_cds_date(date), BusinessDayConvention::Following)
+}
+
+/// Semi-annual roll date in force on `trade_date`: the latest 20 March or
+/// 20 September on or before it.
+///
+/// Since the 2015 convention change, on-the-run contracts only roll on these
+/// two dates.
+pub fn cds_roll_date(trade_date: Date) -> Date {
+    let mut candidate = Date::ymd(trade_date.year(), trade_date.month(), 20);
+    while candidate > trade_date || candidate.month() % 6 != 3 {
+        candidate = candidate.add_months(-1);
+    }
+    candidate
+}
+
+/// Standard (unadjusted) maturity of a CDS traded on `trade_date` with a
+/// tenor of `tenor_months`, under the 2015 semi-annual roll convention.
+///
+/// Trades between 20 March and 19 September mature on 20 June; trades
+/// between 20 September and 19 March mature on 20 December.
+pub fn cds_maturity(trade_date: Date, tenor_months: i32) -> Date {
+    cds_roll_date(trade_date).add_months(tenor_months + 3)
+}
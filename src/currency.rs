@@ -0,0 +1,44 @@
+//! ISO 4217 currencies.
+
+/// ISO 4217 currencies with FX conventions known to this crate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Currency {
+    USD,
+    EUR,
+    GBP,
+    JPY,
+    CHF,
+    CAD,
+    TRY,
+}
+
+impl Currency {
+    /// ISO 4217 code.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Currency::USD => "USD",
+            Currency::EUR => "EUR",
+            Currency::GBP => "GBP",
+            Currency::JPY => "JPY",
+            Currency::CHF => "CHF",
+            Currency::CAD => "CAD",
+            Currency::TRY => "TRY",
+        }
+    }
+
+    /// Currency of the country with ISO 3166-1 alpha-2 code `country`, if it
+    /// is one of ours.
+    pub fn of_country(country: &str) -> Option<Currency> {
+        match country {
+            "US" => Some(Currency::USD),
+            "AT" | "BE" | "CY" | "DE" | "EE" | "ES" | "FI" | "FR" | "GR" | "HR" | "IE" | "IT"
+            | "LT" | "LU" | "LV" | "MT" | "NL" | "PT" | "SI" | "SK" => Some(Currency::EUR),
+            "GB" => Some(Currency::GBP),
+            "JP" => Some(Currency::JPY),
+            "CH" | "LI" => Some(Currency::CHF),
+            "CA" => Some(Currency::CAD),
+            "TR" => Some(Currency::TRY),
+            _ => None,
+        }
+    }
+}
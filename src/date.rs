@@ -1,13 +1,13 @@
 use chrono::{Datelike, Days, NaiveDate, Weekday};
 
 /// Lightweight date wrapper so we can swap out backend if needed.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Date(pub NaiveDate);
 
 impl Date {
     /// Construct a `Date` from ISO year-month-day components.
     #[inline]
-    pub fn ymd(y: i32, m: u32, d: u32) -> Self {
+    pub const fn ymd(y: i32, m: u32, d: u32) -> Self {
         Date(NaiveDate::from_ymd_opt(y, m, d).expect("invalid date"))
     }
 
@@ -41,6 +41,31 @@ impl Date {
         self.0.ordinal()
     }
 
+    /// The `nth` `weekday` of `month` in `year` (`nth > 0`: from the start of
+    /// the month; `nth < 0`: from the end, `-1` being the last).
+    ///
+    /// Returns `None` if no such day exists (e.g. a fifth Monday).
+    pub fn nth_weekday_of_month(year: i32, month: u32, weekday: Weekday, nth: i8) -> Option<Self> {
+        let wd = weekday.num_days_from_monday() as i32;
+        let day = if nth > 0 {
+            let first = Date::ymd(year, month, 1).weekday().num_days_from_monday() as i32;
+            1 + (wd - first).rem_euclid(7) + 7 * (nth as i32 - 1)
+        } else if nth < 0 {
+            let last_day = days_in_month(year, month) as i32;
+            let last = Date::ymd(year, month, last_day as u32)
+                .weekday()
+                .num_days_from_monday() as i32;
+            last_day - (last - wd).rem_euclid(7) - 7 * (-(nth as i32) - 1)
+        } else {
+            return None;
+        };
+        if day >= 1 && day <= days_in_month(year, month) as i32 {
+            Some(Date::ymd(year, month, day as u32))
+        } else {
+            None
+        }
+    }
+
     /// Add `n` days (can be negative) and return the resulting date.
     #[inline]
     pub fn add_days(&self, n: i32) -> Self {
@@ -50,4 +75,56 @@ impl Date {
             Date(self.0 - Days::new((-n) as u64))
         }
     }
+
+    /// Add `n` months (can be negative), clamping the day to the end of the
+    /// target month (e.g. Jan 31 + 1 month = Feb 28/29).
+    #[inline]
+    pub fn add_months(&self, n: i32) -> Self {
+        let total = self.year() * 12 + self.month() as i32 - 1 + n;
+        let y = total.div_euclid(12);
+        let m = total.rem_euclid(12) as u32 + 1;
+        let d = self.day().min(days_in_month(y, m));
+        Date::ymd(y, m, d)
+    }
+
+    /// Number of calendar days from `self` to `other` (negative if `other` is earlier).
+    #[inline]
+    pub fn days_until(&self, other: Date) -> i32 {
+        (other.0 - self.0).num_days() as i32
+    }
+
+    /// Returns `true` if this date's year is a leap year.
+    #[inline]
+    pub fn is_leap_year(&self) -> bool {
+        is_leap_year(self.year())
+    }
+
+    /// Number of days in this date's month.
+    #[inline]
+    pub fn days_in_month(&self) -> u32 {
+        days_in_month(self.year(), self.month())
+    }
+}
+
+/// Returns `true` if `year` is a leap year in the proleptic Gregorian calendar.
+#[inline]
+pub(crate) const fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+/// Number of days in `month` (1-12) of `year`.
+#[inline]
+pub(crate) const fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        _ => 31,
+    }
+}
+
+/// Returns `true` if `year`-`month`-`day` exists.
+#[inline]
+pub(crate) const fn is_valid_date(year: i32, month: u32, day: u32) -> bool {
+    month >= 1 && month <= 12 && day >= 1 && day <= days_in_month(year, month)
 }
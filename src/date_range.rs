@@ -0,0 +1,364 @@
+//! Date ranges and lazy business-day / holiday iterators over them.
+
+use std::iter::FusedIterator;
+use std::ops::{Bound, RangeBounds};
+
+use chrono::{NaiveDate, Weekday};
+
+use crate::calendar::Calendar;
+use crate::date::Date;
+
+/// A contiguous, possibly empty, range of dates.
+///
+/// Built from any Rust range over `Date` (so both inclusive and exclusive
+/// bounds are supported) and stored with inclusive bounds. Every empty range
+/// is stored as [`DateRange::empty`], so empty ranges compare and hash equal.
+///
+/// ```rust
+/// use business_calendar::{Date, DateRange};
+///
+/// let jan = DateRange::new(Date::ymd(2025, 1, 1)..Date::ymd(2025, 2, 1));
+/// assert_eq!(jan.end(), Date::ymd(2025, 1, 31));
+/// assert_eq!(jan.len(), 31);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DateRange {
+    start: Date,
+    end: Date,
+}
+
+impl DateRange {
+    /// Build a range from any bounds over `Date`, e.g. `a..b`, `a..=b` or
+    /// `(Bound::Excluded(a), Bound::Included(b))`.
+    pub fn new<R: RangeBounds<Date>>(range: R) -> Self {
+        let start = match range.start_bound() {
+            Bound::Included(&d) => Some(d),
+            Bound::Excluded(&d) => d.0.succ_opt().map(Date),
+            Bound::Unbounded => Some(Date(NaiveDate::MIN)),
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&d) => Some(d),
+            Bound::Excluded(&d) => d.0.pred_opt().map(Date),
+            Bound::Unbounded => Some(Date(NaiveDate::MAX)),
+        };
+        match (start, end) {
+            (Some(start), Some(end)) => Self::inclusive(start, end),
+            _ => Self::empty(),
+        }
+    }
+
+    /// The inclusive range `[start, end]`; empty if `start > end`.
+    #[inline]
+    pub fn inclusive(start: Date, end: Date) -> Self {
+        if start > end {
+            Self::empty()
+        } else {
+            Self { start, end }
+        }
+    }
+
+    /// The half-open range `[start, end)`.
+    #[inline]
+    pub fn exclusive(start: Date, end: Date) -> Self {
+        Self::new(start..end)
+    }
+
+    /// The ISO week (Monday to Sunday) containing `date`.
+    pub fn week_of(date: Date) -> Self {
+        let monday = date.add_days(-(date.weekday().num_days_from_monday() as i32));
+        Self::inclusive(monday, monday.add_days(6))
+    }
+
+    /// The calendar month containing `date`.
+    pub fn month_of(date: Date) -> Self {
+        Self::inclusive(
+            Date::ymd(date.year(), date.month(), 1),
+            Date::ymd(date.year(), date.month(), date.days_in_month()),
+        )
+    }
+
+    /// The calendar quarter containing `date`.
+    pub fn quarter_of(date: Date) -> Self {
+        let first_month = (date.month() - 1) / 3 * 3 + 1;
+        let start = Date::ymd(date.year(), first_month, 1);
+        Self::exclusive(start, start.add_months(3))
+    }
+
+    /// The calendar year containing `date`.
+    pub fn year_of(date: Date) -> Self {
+        Self::inclusive(Date::ymd(date.year(), 1, 1), Date::ymd(date.year(), 12, 31))
+    }
+
+    /// An empty range.
+    #[inline]
+    pub fn empty() -> Self {
+        Self {
+            start: Date(NaiveDate::MAX),
+            end: Date(NaiveDate::MIN),
+        }
+    }
+
+    /// First date of the range (meaningless if the range is empty).
+    #[inline]
+    pub fn start(&self) -> Date {
+        self.start
+    }
+
+    /// Last date of the range, inclusive (meaningless if the range is empty).
+    #[inline]
+    pub fn end(&self) -> Date {
+        self.end
+    }
+
+    /// Returns `true` if the range contains no dates.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.start > self.end
+    }
+
+    /// Number of calendar days in the range.
+    #[inline]
+    pub fn len(&self) -> usize {
+        if self.is_empty() {
+            0
+        } else {
+            self.start.days_until(self.end) as usize + 1
+        }
+    }
+
+    /// Returns `true` if `date` lies within the range.
+    #[inline]
+    pub fn contains(&self, date: Date) -> bool {
+        self.start <= date && date <= self.end
+    }
+
+    /// Dates common to both ranges (possibly empty).
+    #[inline]
+    pub fn intersection(&self, other: &DateRange) -> DateRange {
+        Self::inclusive(self.start.max(other.start), self.end.min(other.end))
+    }
+}
+
+impl<R: RangeBounds<Date>> From<R> for DateRange {
+    #[inline]
+    fn from(range: R) -> Self {
+        Self::new(range)
+    }
+}
+
+/// Bit for `date`'s weekday in a weekend mask (Monday = bit 0).
+#[inline]
+fn weekday_bit(date: Date) -> u8 {
+    1 << date.weekday().num_days_from_monday()
+}
+
+/// Double-ended cursor over the days of a range that the calendar may keep
+/// open.
+///
+/// Weekdays the calendar is always closed on (see
+/// [`Calendar::is_always_closed_on`]) are skipped by jumping straight over
+/// them rather than testing each one against the calendar.
+#[derive(Debug, Clone)]
+struct WeekdayCursor {
+    front: Date,
+    back: Date,
+    weekend: u8,
+    done: bool,
+}
+
+impl WeekdayCursor {
+    fn new<C: Calendar + ?Sized>(calendar: &C, range: DateRange) -> Self {
+        let mut cursor = Self {
+            front: range.start,
+            back: range.end,
+            weekend: 0,
+            done: range.is_empty(),
+        };
+        if cursor.done {
+            return cursor;
+        }
+
+        let mut weekday = Weekday::Mon;
+        for bit in 0..7 {
+            if calendar.is_always_closed_on(weekday) {
+                cursor.weekend |= 1 << bit;
+            }
+            weekday = weekday.succ();
+        }
+        if cursor.weekend == 0x7f {
+            cursor.done = true;
+            return cursor;
+        }
+
+        if cursor.is_weekend(cursor.front) {
+            match cursor.step(cursor.front, 1) {
+                Some(d) => cursor.front = d,
+                None => cursor.done = true,
+            }
+        }
+        if cursor.is_weekend(cursor.back) {
+            match cursor.step(cursor.back, -1) {
+                Some(d) => cursor.back = d,
+                None => cursor.done = true,
+            }
+        }
+        cursor
+    }
+
+    #[inline]
+    fn is_weekend(&self, date: Date) -> bool {
+        self.weekend & weekday_bit(date) != 0
+    }
+
+    /// Next non-weekend day strictly after (`dir > 0`) or before (`dir < 0`)
+    /// `date`, or `None` past the ends of the supported date range.
+    #[inline]
+    fn step(&self, date: Date, dir: i32) -> Option<Date> {
+        let mut d = date.0;
+        loop {
+            d = if dir > 0 {
+                d.succ_opt()?
+            } else {
+                d.pred_opt()?
+            };
+            if !self.is_weekend(Date(d)) {
+                return Some(Date(d));
+            }
+        }
+    }
+
+    #[inline]
+    fn next_front(&mut self) -> Option<Date> {
+        if self.done || self.front > self.back {
+            return None;
+        }
+        let d = self.front;
+        match self.step(d, 1) {
+            Some(next) if d != self.back => self.front = next,
+            _ => self.done = true,
+        }
+        Some(d)
+    }
+
+    #[inline]
+    fn next_back(&mut self) -> Option<Date> {
+        if self.done || self.front > self.back {
+            return None;
+        }
+        let d = self.back;
+        match self.step(d, -1) {
+            Some(next) if d != self.front => self.back = next,
+            _ => self.done = true,
+        }
+        Some(d)
+    }
+}
+
+/// Lazy iterator over the business days of a calendar within a [`DateRange`].
+///
+/// Created by [`Calendar::business_days`] or [`BusinessDays::new`].
+#[derive(Debug)]
+pub struct BusinessDays<'a, C: ?Sized> {
+    calendar: &'a C,
+    cursor: WeekdayCursor,
+}
+
+impl<C: ?Sized> Clone for BusinessDays<'_, C> {
+    fn clone(&self) -> Self {
+        Self {
+            calendar: self.calendar,
+            cursor: self.cursor.clone(),
+        }
+    }
+}
+
+impl<'a, C: Calendar + ?Sized> BusinessDays<'a, C> {
+    /// Business days of `calendar` in `range`, in ascending order.
+    pub fn new(calendar: &'a C, range: DateRange) -> Self {
+        Self {
+            calendar,
+            cursor: WeekdayCursor::new(calendar, range),
+        }
+    }
+}
+
+impl<C: Calendar + ?Sized> Iterator for BusinessDays<'_, C> {
+    type Item = Date;
+
+    fn next(&mut self) -> Option<Date> {
+        while let Some(d) = self.cursor.next_front() {
+            if self.calendar.is_business_day(d) {
+                return Some(d);
+            }
+        }
+        None
+    }
+}
+
+impl<C: Calendar + ?Sized> DoubleEndedIterator for BusinessDays<'_, C> {
+    fn next_back(&mut self) -> Option<Date> {
+        while let Some(d) = self.cursor.next_back() {
+            if self.calendar.is_business_day(d) {
+                return Some(d);
+            }
+        }
+        None
+    }
+}
+
+impl<C: Calendar + ?Sized> FusedIterator for BusinessDays<'_, C> {}
+
+/// Lazy iterator over the holidays (non-weekend non-business days) of a
+/// calendar within a [`DateRange`].
+///
+/// Created by [`Calendar::holidays`] or [`Holidays::new`].
+#[derive(Debug)]
+pub struct Holidays<'a, C: ?Sized> {
+    calendar: &'a C,
+    cursor: WeekdayCursor,
+}
+
+impl<C: ?Sized> Clone for Holidays<'_, C> {
+    fn clone(&self) -> Self {
+        Self {
+            calendar: self.calendar,
+            cursor: self.cursor.clone(),
+        }
+    }
+}
+
+impl<'a, C: Calendar + ?Sized> Holidays<'a, C> {
+    /// Holidays of `calendar` in `range`, in ascending order.
+    pub fn new(calendar: &'a C, range: DateRange) -> Self {
+        Self {
+            calendar,
+            cursor: WeekdayCursor::new(calendar, range),
+        }
+    }
+}
+
+impl<C: Calendar + ?Sized> Iterator for Holidays<'_, C> {
+    type Item = Date;
+
+    fn next(&mut self) -> Option<Date> {
+        while let Some(d) = self.cursor.next_front() {
+            if self.calendar.is_holiday(d) {
+                return Some(d);
+            }
+        }
+        None
+    }
+}
+
+impl<C: Calendar + ?Sized> DoubleEndedIterator for Holidays<'_, C> {
+    fn next_back(&mut self) -> Option<Date> {
+        while let Some(d) = self.cursor.next_back() {
+            if self.calendar.is_holiday(d) {
+                return Some(d);
+            }
+        }
+        None
+    }
+}
+
+impl<C: Calendar + ?Sized> FusedIterator for Holidays<'_, C> {}
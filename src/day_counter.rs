@@ -0,0 +1,297 @@
+//! Day count conventions for accrual and year-fraction calculations.
+
+use std::fmt;
+
+use crate::calendar::Calendar;
+use crate::date::Date;
+use crate::date_range::{BusinessDays, DateRange};
+
+/// Day count convention used to measure the time between two dates.
+///
+/// All conventions return both a day count and a year fraction. Swapping
+/// `start` and `end` negates the result.
+///
+/// ```rust
+/// use business_calendar::{Date, DayCounter};
+///
+/// let start = Date::ymd(2025, 1, 15);
+/// let end = Date::ymd(2025, 7, 15);
+/// assert_eq!(DayCounter::Actual360.day_count(start, end), 181);
+/// assert_eq!(DayCounter::Thirty360BondBasis.day_count(start, end), 180);
+/// ```
+#[derive(Clone, Copy)]
+pub enum DayCounter<'a> {
+    /// Actual/360: actual days over a 360-day year.
+    Actual360,
+    /// Actual/365 (Fixed): actual days over a 365-day year.
+    Actual365Fixed,
+    /// Actual/365.25: actual days over a 365.25-day year.
+    Actual36525,
+    /// 30/360 Bond Basis (30/360 US without the February rules, ISDA 4.16(f)).
+    Thirty360BondBasis,
+    /// 30E/360 Eurobond Basis (ISDA 4.16(g)).
+    Thirty360Eurobond,
+    /// 30E/360 ISDA (ISDA 4.16(h)).
+    ///
+    /// A last day of February is not rolled to the 30th when it is the
+    /// `termination` date of the instrument.
+    Thirty360Isda { termination: Option<Date> },
+    /// 30/360 German: month ends (including end of February) count as the 30th.
+    Thirty360German,
+    /// Actual/Actual ISDA: days in leap and non-leap years weighted separately.
+    ActualActualIsda,
+    /// Actual/Actual ICMA: actual days over the actual length of the
+    /// reference (coupon) period, times the period length in years.
+    ActualActualIcma,
+    /// Actual/Actual AFB (Euro): whole years counted back from the end date,
+    /// remainder over 365 or 366 depending on whether it spans a 29 February.
+    ActualActualAfb,
+    /// Business/252: business days in `[start, end)` over 252, as used in Brazil.
+    Business252 { calendar: &'a dyn Calendar },
+}
+
+impl DayCounter<'_> {
+    /// Human-readable name of the convention.
+    pub fn name(&self) -> &'static str {
+        match self {
+            DayCounter::Actual360 => "Actual/360",
+            DayCounter::Actual365Fixed => "Actual/365 (Fixed)",
+            DayCounter::Actual36525 => "Actual/365.25",
+            DayCounter::Thirty360BondBasis => "30/360 (Bond Basis)",
+            DayCounter::Thirty360Eurobond => "30E/360 (Eurobond Basis)",
+            DayCounter::Thirty360Isda { .. } => "30E/360 (ISDA)",
+            DayCounter::Thirty360German => "30/360 (German)",
+            DayCounter::ActualActualIsda => "Actual/Actual (ISDA)",
+            DayCounter::ActualActualIcma => "Actual/Actual (ICMA)",
+            DayCounter::ActualActualAfb => "Actual/Actual (AFB)",
+            DayCounter::Business252 { .. } => "Business/252",
+        }
+    }
+
+    /// Number of days between `start` and `end` under this convention.
+    pub fn day_count(&self, start: Date, end: Date) -> i32 {
+        match *self {
+            DayCounter::Thirty360BondBasis
+            | DayCounter::Thirty360Eurobond
+            | DayCounter::Thirty360Isda { .. }
+            | DayCounter::Thirty360German => self.thirty_360(start, end),
+            DayCounter::Business252 { calendar } => business_days_between(calendar, start, end),
+            _ => start.days_until(end),
+        }
+    }
+
+    /// Year fraction between `start` and `end`.
+    ///
+    /// For Actual/Actual ICMA the accrual period itself is used as the
+    /// reference period; see [`DayCounter::year_fraction_with_reference`].
+    pub fn year_fraction(&self, start: Date, end: Date) -> f64 {
+        self.year_fraction_with_reference(start, end, start.min(end), start.max(end))
+    }
+
+    /// Year fraction between `start` and `end` given the reference (regular
+    /// coupon) period `[ref_start, ref_end]`.
+    ///
+    /// Only Actual/Actual ICMA uses the reference period; other conventions
+    /// ignore it. As for the other conventions, `end` before `start` gives
+    /// the negated fraction.
+    ///
+    /// # Panics
+    ///
+    /// For Actual/Actual ICMA, if `start != end` and the reference period
+    /// does not end after it starts.
+    pub fn year_fraction_with_reference(
+        &self,
+        start: Date,
+        end: Date,
+        ref_start: Date,
+        ref_end: Date,
+    ) -> f64 {
+        match *self {
+            DayCounter::Actual360 => start.days_until(end) as f64 / 360.0,
+            DayCounter::Actual365Fixed => start.days_until(end) as f64 / 365.0,
+            DayCounter::Actual36525 => start.days_until(end) as f64 / 365.25,
+            DayCounter::Thirty360BondBasis
+            | DayCounter::Thirty360Eurobond
+            | DayCounter::Thirty360Isda { .. }
+            | DayCounter::Thirty360German => self.thirty_360(start, end) as f64 / 360.0,
+            DayCounter::ActualActualIsda => actual_actual_isda(start, end),
+            DayCounter::ActualActualIcma => actual_actual_icma(start, end, ref_start, ref_end),
+            DayCounter::ActualActualAfb => actual_actual_afb(start, end),
+            DayCounter::Business252 { calendar } => {
+                business_days_between(calendar, start, end) as f64 / 252.0
+            }
+        }
+    }
+
+    /// 30/360 day count with the day-of-month adjustments of this convention.
+    fn thirty_360(&self, start: Date, end: Date) -> i32 {
+        let mut d1 = start.day();
+        let mut d2 = end.day();
+        let start_is_eom = d1 == start.days_in_month();
+        let end_is_eom = d2 == end.days_in_month();
+
+        match *self {
+            DayCounter::Thirty360BondBasis => {
+                if d1 == 31 {
+                    d1 = 30;
+                }
+                if d2 == 31 && d1 == 30 {
+                    d2 = 30;
+                }
+            }
+            DayCounter::Thirty360Eurobond => {
+                d1 = d1.min(30);
+                d2 = d2.min(30);
+            }
+            DayCounter::Thirty360Isda { termination } => {
+                if start_is_eom {
+                    d1 = 30;
+                }
+                if end_is_eom && !(end.month() == 2 && termination == Some(end)) {
+                    d2 = 30;
+                }
+            }
+            DayCounter::Thirty360German => {
+                if start_is_eom {
+                    d1 = 30;
+                }
+                if end_is_eom {
+                    d2 = 30;
+                }
+            }
+            _ => unreachable!("not a 30/360 convention"),
+        }
+
+        360 * (end.year() - start.year())
+            + 30 * (end.month() as i32 - start.month() as i32)
+            + (d2 as i32 - d1 as i32)
+    }
+}
+
+impl fmt::Debug for DayCounter<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Business days in `[start, end)` according to `calendar` (negative if reversed).
+fn business_days_between(calendar: &dyn Calendar, start: Date, end: Date) -> i32 {
+    if start > end {
+        return -business_days_between(calendar, end, start);
+    }
+    BusinessDays::new(calendar, DateRange::exclusive(start, end)).count() as i32
+}
+
+fn days_in_year(year: i32) -> f64 {
+    if crate::date::is_leap_year(year) {
+        366.0
+    } else {
+        365.0
+    }
+}
+
+fn actual_actual_isda(start: Date, end: Date) -> f64 {
+    if start == end {
+        return 0.0;
+    }
+    if start > end {
+        return -actual_actual_isda(end, start);
+    }
+    let (y1, y2) = (start.year(), end.year());
+    let mut sum = (y2 - y1 - 1) as f64;
+    sum += start.days_until(Date::ymd(y1 + 1, 1, 1)) as f64 / days_in_year(y1);
+    sum += Date::ymd(y2, 1, 1).days_until(end) as f64 / days_in_year(y2);
+    sum
+}
+
+fn actual_actual_icma(start: Date, end: Date, ref_start: Date, ref_end: Date) -> f64 {
+    if start == end {
+        return 0.0;
+    }
+    assert!(
+        ref_start < ref_end,
+        "Actual/Actual ICMA: reference period {} to {} is empty or inverted",
+        ref_start.0,
+        ref_end.0
+    );
+    if start > end {
+        return -actual_actual_icma(end, start, ref_start, ref_end);
+    }
+
+    let (mut ref_start, mut ref_end) = (ref_start, ref_end);
+    let mut months = (12.0 * ref_start.days_until(ref_end) as f64 / 365.0).round() as i32;
+    if months == 0 {
+        // No usable reference period: fall back to one year from `start`.
+        ref_start = start;
+        ref_end = start.add_months(12);
+        months = 12;
+    }
+    let period = months as f64 / 12.0;
+
+    if end <= ref_end {
+        if start >= ref_start {
+            // Regular (or short) period inside the reference period.
+            period * start.days_until(end) as f64 / ref_start.days_until(ref_end) as f64
+        } else {
+            // Long first period: split at the start of the reference period.
+            let previous_ref = ref_start.add_months(-months);
+            if end > ref_start {
+                actual_actual_icma(start, ref_start, previous_ref, ref_start)
+                    + actual_actual_icma(ref_start, end, ref_start, ref_end)
+            } else {
+                actual_actual_icma(start, end, previous_ref, ref_start)
+            }
+        }
+    } else {
+        // Long last period: whole notional periods after `ref_end`, then the stub.
+        let mut sum = actual_actual_icma(start, ref_end, ref_start, ref_end);
+        let mut i = 0;
+        loop {
+            let new_start = ref_end.add_months(months * i);
+            let new_end = ref_end.add_months(months * (i + 1));
+            if end < new_end {
+                return sum + actual_actual_icma(new_start, end, new_start, new_end);
+            }
+            sum += period;
+            i += 1;
+        }
+    }
+}
+
+fn actual_actual_afb(start: Date, end: Date) -> f64 {
+    if start == end {
+        return 0.0;
+    }
+    if start > end {
+        return -actual_actual_afb(end, start);
+    }
+
+    // Count whole years backwards from `end`.
+    let mut new_end = end;
+    let mut whole_years = 0;
+    loop {
+        let mut temp = new_end.add_months(-12);
+        if temp.month() == 2 && temp.day() == 28 && temp.is_leap_year() {
+            temp = temp.add_days(1);
+        }
+        if temp < start {
+            break;
+        }
+        whole_years += 1;
+        new_end = temp;
+    }
+
+    let spans_feb_29 = |year: i32| {
+        crate::date::is_leap_year(year) && {
+            let feb_29 = Date::ymd(year, 2, 29);
+            new_end > feb_29 && start <= feb_29
+        }
+    };
+    let den = if spans_feb_29(new_end.year()) || spans_feb_29(start.year()) {
+        366.0
+    } else {
+        365.0
+    };
+
+    whole_years as f64 + start.days_until(new_end) as f64 / den
+}
@@ -0,0 +1,312 @@
+//! Calendars derived from a [`StaticCalendar`] by removing, replacing or
+//! adding rules.
+
+use std::borrow::Cow;
+use std::sync::OnceLock;
+
+use chrono::Weekday;
+
+use crate::calendar::Calendar;
+use crate::date::Date;
+use crate::explain::Explanation;
+use crate::rules::{HolidayRule, NamedRule, Occurrence, RuleRef, YearRange};
+use crate::static_calendar::StaticCalendar;
+
+/// A [`StaticCalendar`] with some rules excluded, replaced or overlaid.
+///
+/// The effective rule table is, in order: the base rules not excluded by
+/// name and not replaced, then the replacement rules, then the overlay
+/// rules, keeping only rules known at the [`DerivedCalendar::as_of`] date
+/// if one is set. Replacement rules take the place of every base rule with
+/// the same name; overlay rules (typically [`NamedRule::working_day`]
+/// exceptions) are simply added.
+///
+/// [`RuleRef::Index`] references, in any of these rules, index into the
+/// base table and are remapped into the effective one: a reference to a
+/// replaced rule follows its replacement, and a reference to a removed rule
+/// no longer matches.
+///
+/// Derived calendars can be built at run time from a base calendar:
+///
+/// ```rust
+/// use business_calendar::markets::us::settlement::US_SETTLEMENT;
+/// use business_calendar::{Calendar, Date};
+///
+/// let no_columbus = US_SETTLEMENT.without("Columbus Day");
+/// let columbus_day = Date::ymd(2025, 10, 13);
+/// assert!(!US_SETTLEMENT.is_business_day(columbus_day));
+/// assert!(no_columbus.is_business_day(columbus_day));
+/// ```
+///
+/// or as `static`s with [`DerivedCalendar::new`].
+///
+/// Views built at run time are named after the calendar they come from,
+/// with a ` (modified)` suffix once rules are removed, replaced or added and
+/// an ` as of YYYY-MM-DD` suffix for [`DerivedCalendar::as_of`] views, so
+/// that they are never mistaken for the shipped calendar;
+/// [`DerivedCalendar::named`] sets a name of the caller's choosing instead.
+#[derive(Debug)]
+pub struct DerivedCalendar {
+    /// Name given to [`DerivedCalendar::new`], before any suffix.
+    label: &'static str,
+    name: Cow<'static, str>,
+    /// Whether the name was set by [`DerivedCalendar::named`].
+    named: bool,
+    /// Whether rules were changed at run time.
+    modified: bool,
+    base: &'static StaticCalendar<'static>,
+    without: Cow<'static, [&'static str]>,
+    replace: Cow<'static, [NamedRule]>,
+    overlay: Cow<'static, [NamedRule]>,
+    as_of: Option<Date>,
+    rules: OnceLock<Vec<NamedRule>>,
+}
+
+impl DerivedCalendar {
+    /// `base` without the rules named in `without`, with the rules named in
+    /// `replace` replaced and the `overlay` rules added.
+    pub const fn new(
+        name: &'static str,
+        base: &'static StaticCalendar<'static>,
+        without: &'static [&'static str],
+        replace: &'static [NamedRule],
+        overlay: &'static [NamedRule],
+    ) -> Self {
+        Self {
+            label: name,
+            name: Cow::Borrowed(name),
+            named: false,
+            modified: false,
+            base,
+            without: Cow::Borrowed(without),
+            replace: Cow::Borrowed(replace),
+            overlay: Cow::Borrowed(overlay),
+            as_of: None,
+            rules: OnceLock::new(),
+        }
+    }
+
+    /// This calendar renamed to `name`, which later changes leave as is.
+    pub fn named(mut self, name: impl Into<Cow<'static, str>>) -> Self {
+        self.name = name.into();
+        self.named = true;
+        self
+    }
+
+    /// This calendar without the rules named `name`.
+    pub fn without(mut self, name: &'static str) -> Self {
+        self.without.to_mut().push(name);
+        self.modified()
+    }
+
+    /// This calendar with every rule named like `rule` replaced by `rule`
+    /// (several replacements may share a name).
+    pub fn replace(mut self, rule: NamedRule) -> Self {
+        self.replace.to_mut().push(rule);
+        self.modified()
+    }
+
+    /// This calendar with `rule` added on top of the others.
+    pub fn overlay(mut self, rule: NamedRule) -> Self {
+        self.overlay.to_mut().push(rule);
+        self.modified()
+    }
+
+    fn modified(mut self) -> Self {
+        self.modified = true;
+        self.rules = OnceLock::new();
+        self.renamed()
+    }
+
+    /// Refreshes the default name after a change.
+    fn renamed(mut self) -> Self {
+        if self.named {
+            return self;
+        }
+        let mut name = self.label.to_owned();
+        if self.modified {
+            name.push_str(" (modified)");
+        }
+        if let Some(date) = self.as_of {
+            name.push_str(&format!(" as of {}", date.0));
+        }
+        self.name = Cow::Owned(name);
+        self
+    }
+
+    /// This calendar as it was known on `knowledge_date`: without the rules
+    /// announced after it (see [`NamedRule::announced_on`]).
+    ///
+    /// ```rust
+    /// use business_calendar::markets::us::nyse::US_NYSE;
+    /// use business_calendar::{Calendar, Date};
+    ///
+    /// let sandy = Date::ymd(2012, 10, 29);
+    /// assert!(!US_NYSE.is_business_day(sandy));
+    /// assert!(US_NYSE.as_of(Date::ymd(2012, 10, 26)).is_business_day(sandy));
+    /// ```
+    pub fn as_of(mut self, knowledge_date: Date) -> Self {
+        self.as_of = Some(knowledge_date);
+        self.rules = OnceLock::new();
+        self.renamed()
+    }
+
+    /// The knowledge date set by [`DerivedCalendar::as_of`], if any.
+    #[inline]
+    pub fn knowledge_date(&self) -> Option<Date> {
+        self.as_of
+    }
+
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The calendar this one is derived from.
+    #[inline]
+    pub fn base(&self) -> &'static StaticCalendar<'static> {
+        self.base
+    }
+
+    /// The effective rule table, built on first use.
+    pub fn rules(&self) -> &[NamedRule] {
+        self.rules.get_or_init(|| {
+            let known = |rule: &NamedRule| self.as_of.is_none_or(|date| rule.is_known_at(date));
+            let replaced = |rule: &NamedRule| self.replace.iter().any(|r| r.name == rule.name);
+            let kept = |rule: &NamedRule| {
+                !self.without.contains(&rule.name) && !replaced(rule) && known(rule)
+            };
+
+            // Position of each base rule in the effective table.
+            let mut next = 0;
+            let positions: Vec<Option<usize>> = (self.base.rules.iter())
+                .map(|rule| {
+                    kept(rule).then(|| {
+                        next += 1;
+                        next - 1
+                    })
+                })
+                .collect();
+            let remap = |mut rule: NamedRule| {
+                if let HolidayRule::Relative { base, .. } = &mut rule.rule {
+                    if let RuleRef::Index(i) = *base {
+                        *base = match positions.get(i) {
+                            Some(Some(position)) => RuleRef::Index(*position),
+                            Some(None) if replaced(&self.base.rules[i]) => {
+                                RuleRef::Name(self.base.rules[i].name)
+                            }
+                            // The referenced rule is gone: match nothing.
+                            _ => RuleRef::Index(usize::MAX),
+                        };
+                    }
+                }
+                rule
+            };
+
+            let base = self.base.rules.iter().filter(|rule| kept(rule));
+            base.chain(self.replace.iter().filter(|rule| known(rule)))
+                .chain(self.overlay.iter().filter(|rule| known(rule)))
+                .copied()
+                .map(remap)
+                .collect()
+        })
+    }
+
+    /// This calendar as a [`StaticCalendar`] over its effective rules.
+    #[inline]
+    pub fn as_static(&self) -> StaticCalendar<'_> {
+        StaticCalendar::new(&self.name, self.base.weekend, self.rules())
+            .supported_over(self.base.horizon)
+    }
+}
+
+impl Clone for DerivedCalendar {
+    fn clone(&self) -> Self {
+        Self {
+            label: self.label,
+            name: self.name.clone(),
+            named: self.named,
+            modified: self.modified,
+            base: self.base,
+            without: self.without.clone(),
+            replace: self.replace.clone(),
+            overlay: self.overlay.clone(),
+            as_of: self.as_of,
+            rules: self.rules.clone(),
+        }
+    }
+}
+
+impl StaticCalendar<'static> {
+    /// A calendar derived from this one, with no changes yet.
+    pub fn derive(&'static self) -> DerivedCalendar {
+        DerivedCalendar::new(self.name, self, &[], &[], &[])
+    }
+
+    /// This calendar without the rules named `name`.
+    pub fn without(&'static self, name: &'static str) -> DerivedCalendar {
+        self.derive().without(name)
+    }
+
+    /// This calendar with every rule named like `rule` replaced by `rule`.
+    pub fn replace(&'static self, rule: NamedRule) -> DerivedCalendar {
+        self.derive().replace(rule)
+    }
+
+    /// This calendar with `rule` added on top of its own rules.
+    pub fn overlay(&'static self, rule: NamedRule) -> DerivedCalendar {
+        self.derive().overlay(rule)
+    }
+
+    /// This calendar as it was known on `knowledge_date`; see
+    /// [`DerivedCalendar::as_of`].
+    pub fn as_of(&'static self, knowledge_date: Date) -> DerivedCalendar {
+        self.derive().as_of(knowledge_date)
+    }
+}
+
+impl Calendar for DerivedCalendar {
+    #[inline]
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn supported_years(&self) -> YearRange {
+        self.as_static().supported_years()
+    }
+
+    #[inline]
+    fn is_business_day(&self, date: Date) -> bool {
+        self.as_static().is_business_day(date)
+    }
+
+    #[inline]
+    fn is_weekend(&self, date: Date) -> bool {
+        self.as_static().is_weekend(date)
+    }
+
+    #[inline]
+    fn is_always_closed_on(&self, weekday: Weekday) -> bool {
+        self.as_static().is_always_closed_on(weekday)
+    }
+
+    fn matching_rules(&self, date: Date) -> Vec<NamedRule> {
+        Calendar::matching_rules(&self.as_static(), date)
+    }
+
+    fn matching_occurrences(&self, date: Date) -> Vec<Occurrence> {
+        self.as_static().matching_occurrences(date)
+    }
+
+    fn explain(&self, date: Date) -> Explanation {
+        self.as_static().explain(date)
+    }
+
+    fn next_holiday(&self, date: Date) -> Option<Occurrence> {
+        self.as_static().next_holiday(date)
+    }
+
+    fn previous_holiday(&self, date: Date) -> Option<Occurrence> {
+        self.as_static().previous_holiday(date)
+    }
+}
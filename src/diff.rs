@@ -0,0 +1,75 @@
+//! Day-by-day comparison of two calendars.
+
+use crate::calendar::Calendar;
+use crate::date::Date;
+use crate::date_range::DateRange;
+use crate::rules::NamedRule;
+
+/// One of the two calendars being compared.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Side {
+    Left,
+    Right,
+}
+
+/// A date on which two calendars disagree.
+#[derive(Debug, Clone)]
+pub struct Difference {
+    pub date: Date,
+    /// The calendar that considers `date` a business day.
+    pub business_day_in: Side,
+    /// Rules of the other calendar that make `date` a holiday there. Empty
+    /// when that calendar is not rule-based or closes for another reason
+    /// (e.g. a different weekend).
+    pub rules: Vec<NamedRule>,
+}
+
+/// Dates in `range` on which `left` and `right` disagree about being a
+/// business day, in ascending order.
+///
+/// ```rust
+/// use business_calendar::diff::{diff, Side};
+/// use business_calendar::markets::us::{USMarket, UnitedStates};
+/// use business_calendar::{Date, DateRange};
+///
+/// let settlement = UnitedStates::new(USMarket::Settlement);
+/// let nyse = UnitedStates::new(USMarket::NYSE);
+/// let year = DateRange::year_of(Date::ymd(2025, 1, 1));
+///
+/// let diffs = diff(&settlement, &nyse, year);
+/// let good_friday = diffs.iter().find(|d| d.date == Date::ymd(2025, 4, 18)).unwrap();
+/// assert_eq!(good_friday.business_day_in, Side::Left);
+/// assert_eq!(good_friday.rules[0].name, "Good Friday");
+/// ```
+pub fn diff<L, R>(left: &L, right: &R, range: DateRange) -> Vec<Difference>
+where
+    L: Calendar + ?Sized,
+    R: Calendar + ?Sized,
+{
+    let mut differences = Vec::new();
+    if range.is_empty() {
+        return differences;
+    }
+
+    let mut date = range.start();
+    loop {
+        let in_left = left.is_business_day(date);
+        if in_left != right.is_business_day(date) {
+            let (business_day_in, rules) = if in_left {
+                (Side::Left, right.matching_rules(date))
+            } else {
+                (Side::Right, left.matching_rules(date))
+            };
+            differences.push(Difference {
+                date,
+                business_day_in,
+                rules,
+            });
+        }
+        if date == range.end() {
+            break;
+        }
+        date = date.add_days(1);
+    }
+    differences
+}
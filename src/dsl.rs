@@ -0,0 +1,347 @@
+//! Compact rule syntax for the [`calendar!`](crate::calendar!) and
+//! [`derived_calendar!`](crate::derived_calendar!) macros.
+//!
+//! Each function returns an unnamed [`NamedRule`] applying in every year;
+//! chain [`NamedRule::observed`], [`NamedRule::since`],
+//! [`NamedRule::until`], [`NamedRule::between`],
+//! [`NamedRule::with_category`] and friends to refine it. The macro names
+//! each rule and checks the whole table with [`validate`] at compile time.
+
+use crate::date::is_valid_date;
+use crate::rules::{HolidayRuleLike, NamedRule, RuleRef};
+
+pub use crate::category::HolidayCategory::{
+    ObservedSubstitute, SpecialOneOff, Statutory, Unscheduled,
+};
+pub use crate::date::Date;
+pub use crate::rules::{
+    DateTable, HolidayRule, RelativeCondition, RuleEffect, YearCycle, YearRange,
+};
+pub use chrono::Weekday::{Fri, Mon, Sat, Sun, Thu, Tue, Wed};
+
+/// Defines a `static` [`StaticCalendar`](crate::StaticCalendar) from a
+/// compact rule table.
+///
+/// Every rule is `"Name" => rule`, where `rule` is built from the functions
+/// of [`dsl`](crate::dsl) (in scope inside the macro). The weekend defaults
+/// to Saturday and Sunday, and the horizon (see
+/// [`Calendar::supported_years`](crate::Calendar::supported_years)) to
+/// every year.
+///
+/// ```rust
+/// use business_calendar::{calendar, Calendar, Date, YearRange};
+///
+/// calendar! {
+///     /// Exchange of Examplestan.
+///     pub static EXAMPLE = "Example Exchange", horizon(1990, 2030) {
+///         "New Year's Day" => fixed(1, 1).observed(),
+///         "Good Friday" => easter(-3),
+///         "Founders' Day" => nth(9, Mon, 1).since(1990),
+///         "Market Outage" => one_off(2021, 3, 3).with_category(Unscheduled),
+///     }
+/// }
+///
+/// assert!(!EXAMPLE.is_business_day(Date::ymd(2023, 1, 2)));
+/// assert_eq!(EXAMPLE.rules.len(), 4);
+/// assert_eq!(EXAMPLE.supported_years(), YearRange::between(1990, 2030));
+/// ```
+///
+/// Impossible dates, empty year cycles and recurring rules of the same name
+/// with overlapping year ranges are compile errors:
+///
+/// ```compile_fail
+/// use business_calendar::calendar;
+///
+/// calendar! {
+///     static BAD = "Bad" {
+///         "Leap Day" => fixed(2, 30),
+///     }
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use business_calendar::calendar;
+///
+/// calendar! {
+///     static BAD = "Bad" {
+///         "Census Day" => fixed(4, 1).every(YearCycle::new(0, 0)),
+///     }
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use business_calendar::calendar;
+///
+/// calendar! {
+///     static BAD = "Bad" {
+///         "Founders' Day" => fixed(9, 1).until(2000),
+///         "Founders' Day" => nth(9, Mon, 1).since(1990),
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! calendar {
+    (
+        $(#[$attr:meta])*
+        $vis:vis static $ident:ident = $name:literal
+            $(, weekend($w1:ident, $w2:ident))?
+            $(, horizon($first:literal, $last:literal))? {
+            $($rule_name:literal => $rule:expr),* $(,)?
+        }
+    ) => {
+        $(#[$attr])*
+        $vis static $ident: $crate::StaticCalendar<'static> = {
+            #[allow(unused_imports)]
+            use $crate::dsl::*;
+            const RULES: &[$crate::NamedRule] = &[$(($rule).named($rule_name)),*];
+            const _: () = $crate::dsl::validate(RULES);
+            $crate::StaticCalendar::new($name, $crate::calendar!(@weekend $($w1, $w2)?), RULES)
+                $(.supported_over($crate::YearRange::between($first, $last)))?
+        };
+    };
+    (@weekend) => {
+        [$crate::dsl::Sat, $crate::dsl::Sun]
+    };
+    (@weekend $w1:ident, $w2:ident) => {
+        [$crate::dsl::$w1, $crate::dsl::$w2]
+    };
+}
+
+/// Defines a `static` [`DerivedCalendar`](crate::DerivedCalendar) from a
+/// base calendar and compact rule tables.
+///
+/// The base is the path of a `static` [`StaticCalendar`](crate::StaticCalendar);
+/// `without` lists the names of base rules to drop, and `replace` and
+/// `overlay` are rule tables written as in [`calendar!`](crate::calendar!).
+/// All three sections are required but may be empty.
+///
+/// ```rust
+/// use business_calendar::{calendar, derived_calendar, Calendar, Date};
+///
+/// calendar! {
+///     pub static EXCHANGE = "Example Exchange" {
+///         "New Year's Day" => fixed(1, 1).observed(),
+///         "Founders' Day" => nth(9, Mon, 1),
+///     }
+/// }
+///
+/// derived_calendar! {
+///     /// Clearing house of Examplestan: open on Founders' Day, closed on
+///     /// the Friday before the first Monday of June.
+///     pub static CLEARING = "Example Clearing", base(EXCHANGE) {
+///         without: ["Founders' Day"],
+///         replace: {},
+///         overlay: {
+///             "Settlement Holiday" => on_or_after(6, 1, Mon).shifted(-3),
+///         },
+///     }
+/// }
+///
+/// assert!(CLEARING.is_business_day(Date::ymd(2025, 9, 1)));
+/// assert!(!CLEARING.is_business_day(Date::ymd(2025, 5, 30)));
+/// ```
+#[macro_export]
+macro_rules! derived_calendar {
+    (
+        $(#[$attr:meta])*
+        $vis:vis static $ident:ident = $name:literal, base($base:path) {
+            without: [$($without:literal),* $(,)?],
+            replace: { $($replace_name:literal => $replace:expr),* $(,)? },
+            overlay: { $($overlay_name:literal => $overlay:expr),* $(,)? } $(,)?
+        }
+    ) => {
+        $(#[$attr])*
+        $vis static $ident: $crate::DerivedCalendar = {
+            #[allow(unused_imports)]
+            use $crate::dsl::*;
+            const REPLACE: &[$crate::NamedRule] = &[$(($replace).named($replace_name)),*];
+            const OVERLAY: &[$crate::NamedRule] = &[$(($overlay).named($overlay_name)),*];
+            const _: () = $crate::dsl::validate(REPLACE);
+            const _: () = $crate::dsl::validate(OVERLAY);
+            $crate::DerivedCalendar::new($name, &$base, &[$($without),*], REPLACE, OVERLAY)
+        };
+    };
+}
+
+const fn unnamed(rule: HolidayRule) -> NamedRule {
+    NamedRule::new("", rule)
+}
+
+/// `month`/`day` every year.
+pub const fn fixed(month: u32, day: u32) -> NamedRule {
+    unnamed(HolidayRule::Fixed {
+        month,
+        day,
+        years: YearRange::always(),
+    })
+}
+
+/// The `n`th `weekday` of `month` (`n < 0` counts from the end).
+pub const fn nth(month: u32, weekday: chrono::Weekday, n: i8) -> NamedRule {
+    unnamed(HolidayRule::NthWeekday {
+        month,
+        weekday,
+        nth: n,
+        years: YearRange::always(),
+    })
+}
+
+/// The last `weekday` of `month`.
+pub const fn last(month: u32, weekday: chrono::Weekday) -> NamedRule {
+    nth(month, weekday, -1)
+}
+
+/// `offset` days from Western Easter Monday (Good Friday is `-3`).
+pub const fn easter(offset: i32) -> NamedRule {
+    unnamed(HolidayRule::EasterOffset {
+        western: true,
+        offset,
+        years: YearRange::always(),
+    })
+}
+
+/// `offset` days from Orthodox Easter Monday.
+pub const fn orthodox_easter(offset: i32) -> NamedRule {
+    unnamed(HolidayRule::EasterOffset {
+        western: false,
+        offset,
+        years: YearRange::always(),
+    })
+}
+
+/// The first `weekday` on or after `month`/`day`.
+pub const fn on_or_after(month: u32, day: u32, weekday: chrono::Weekday) -> NamedRule {
+    unnamed(HolidayRule::WeekdayOnOrAfter {
+        month,
+        day,
+        weekday,
+        offset: 0,
+        years: YearRange::always(),
+    })
+}
+
+/// The last `weekday` on or before `month`/`day`.
+pub const fn on_or_before(month: u32, day: u32, weekday: chrono::Weekday) -> NamedRule {
+    unnamed(HolidayRule::WeekdayOnOrBefore {
+        month,
+        day,
+        weekday,
+        offset: 0,
+        years: YearRange::always(),
+    })
+}
+
+/// The first `weekday` strictly after `month`/`day`.
+pub const fn after(month: u32, day: u32, weekday: chrono::Weekday) -> NamedRule {
+    unnamed(HolidayRule::WeekdayAfter {
+        month,
+        day,
+        weekday,
+        offset: 0,
+        years: YearRange::always(),
+    })
+}
+
+/// `offset` days from the holiday named `base`, if `condition` holds.
+pub const fn relative(base: &'static str, offset: i32, condition: RelativeCondition) -> NamedRule {
+    unnamed(HolidayRule::Relative {
+        base: RuleRef::Name(base),
+        offset,
+        condition,
+        years: YearRange::always(),
+    })
+}
+
+/// A single date.
+pub const fn one_off(year: i32, month: u32, day: u32) -> NamedRule {
+    unnamed(HolidayRule::OneOff { year, month, day })
+}
+
+/// Every date of `table`.
+pub const fn dates(table: DateTable) -> NamedRule {
+    unnamed(HolidayRule::Dates(table))
+}
+
+/// A user-supplied rule.
+pub const fn custom(rule: &'static dyn HolidayRuleLike) -> NamedRule {
+    unnamed(HolidayRule::Custom(rule))
+}
+
+/// Any [`HolidayRule`], for shapes the functions above do not cover.
+pub const fn rule(rule: HolidayRule) -> NamedRule {
+    unnamed(rule)
+}
+
+/// Checks a rule table, panicking (at compile time in `const` context) on
+/// impossible dates and on recurring rules with the same name and effect
+/// whose year ranges overlap.
+pub const fn validate(rules: &[NamedRule]) {
+    let mut i = 0;
+    while i < rules.len() {
+        check_dates(&rules[i].rule);
+        let mut j = i + 1;
+        while j < rules.len() {
+            if overlap(&rules[i], &rules[j]) {
+                panic!("calendar!: rules with the same name have overlapping year ranges");
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+}
+
+const fn check_dates(rule: &HolidayRule) {
+    match *rule {
+        // Checked against a leap year, so that 29 February is allowed.
+        HolidayRule::Fixed { month, day, .. }
+        | HolidayRule::WeekendAdjustedFixed { month, day, .. }
+        | HolidayRule::WeekdayOnOrAfter { month, day, .. }
+        | HolidayRule::WeekdayOnOrBefore { month, day, .. }
+        | HolidayRule::WeekdayAfter { month, day, .. }
+            if !is_valid_date(2000, month, day) =>
+        {
+            panic!("calendar!: impossible month/day")
+        }
+        HolidayRule::NthWeekday { month, nth, .. }
+            if month < 1 || month > 12 || nth == 0 || nth < -5 || nth > 5 =>
+        {
+            panic!("calendar!: impossible nth weekday")
+        }
+        HolidayRule::OneOff { year, month, day } if !is_valid_date(year, month, day) => {
+            panic!("calendar!: impossible one-off date")
+        }
+        _ => {}
+    }
+}
+
+/// Returns `true` if `a` and `b` are recurring rules of the same name and
+/// effect that can both apply in some year.
+const fn overlap(a: &NamedRule, b: &NamedRule) -> bool {
+    let (Some(ya), Some(yb)) = (a.rule.years(), b.rule.years()) else {
+        return false;
+    };
+    if !str_eq(a.name, b.name) || a.effect as u8 != b.effect as u8 {
+        return false;
+    }
+    if let (Some(ca), Some(cb)) = (a.cycle, b.cycle) {
+        if ca.modulus == cb.modulus && ca.remainder != cb.remainder {
+            return false;
+        }
+    }
+    ya.start <= yb.end && yb.start <= ya.end
+}
+
+const fn str_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
@@ -6,6 +6,12 @@ pub mod western;
 pub use orthodox::OrthodoxEasterMondayTable;
 pub use western::EasterMondayTable as WesternEasterMondayTable;
 
+/// First year covered by the Easter tables.
+pub const FIRST_YEAR: i32 = 1901;
+
+/// Last year covered by the Easter tables.
+pub const LAST_YEAR: i32 = 2199;
+
 /// Day-of-year (1-based) of Western Easter Monday for `year` in 1901–2199.
 #[inline]
 pub const fn western_easter_monday(year: i32) -> u32 {
@@ -0,0 +1,27 @@
+//! Structured explanations of why a date is (or is not) a business day.
+
+use crate::date::Date;
+use crate::rules::Occurrence;
+
+/// Why `date` is or is not a business day in a calendar.
+///
+/// Produced by [`Calendar::explain`](crate::Calendar::explain).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Explanation {
+    pub date: Date,
+    pub business_day: bool,
+    /// `true` if `date` falls on the calendar's weekend.
+    pub weekend: bool,
+    /// Every holiday rule matching `date`, with its actual and observed dates.
+    pub holidays: Vec<Occurrence>,
+    /// Market-specific overrides that applied on `date`.
+    pub overrides: Vec<Override>,
+}
+
+/// A market-specific exception to a calendar's rules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Override {
+    pub description: &'static str,
+    /// `true` if the override makes the date a business day, `false` if it closes it.
+    pub business_day: bool,
+}
@@ -0,0 +1,200 @@
+//! FX spot and forward value dates for currency pairs.
+//!
+//! Spot is T+2 for most pairs and T+1 for USD/CAD and USD/TRY. Intermediate
+//! days only need to be business days in the non-USD currencies (a USD
+//! holiday on T+1 does not block spot), but the value date itself must be a
+//! business day in both currencies and in USD.
+
+use std::str::FromStr;
+
+use chrono::Weekday;
+
+use crate::calendar::{BusinessDayConvention, Calendar};
+use crate::date::Date;
+use crate::markets::eu::EU_TARGET;
+use crate::markets::uk::{UKMarket, UnitedKingdom};
+use crate::markets::us::{USMarket, UnitedStates};
+use crate::period::{ParsePeriodError, Period, TimeUnit};
+
+pub use crate::currency::Currency;
+
+static USD_CALENDAR: UnitedStates = UnitedStates {
+    market: USMarket::Settlement,
+};
+static GBP_CALENDAR: UnitedKingdom = UnitedKingdom {
+    market: UKMarket::Settlement,
+};
+
+impl Currency {
+    /// Settlement calendar of this currency, if this crate provides one.
+    ///
+    /// EUR settles on [TARGET](crate::markets::eu::EU_TARGET) days.
+    /// Currencies without a calendar are treated as open on every weekday.
+    pub fn calendar(&self) -> Option<&'static dyn Calendar> {
+        match self {
+            Currency::USD => Some(&USD_CALENDAR),
+            Currency::EUR => Some(&EU_TARGET),
+            Currency::GBP => Some(&GBP_CALENDAR),
+            // No Japan, Switzerland, Canada or Turkey calendars yet.
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if `date` is a settlement day for this currency.
+    #[inline]
+    pub fn is_business_day(&self, date: Date) -> bool {
+        match self.calendar() {
+            Some(cal) => cal.is_business_day(date),
+            None => !matches!(date.weekday(), Weekday::Sat | Weekday::Sun),
+        }
+    }
+}
+
+/// Tenor of an FX forward or swap leg.
+///
+/// The money-market tenors settle before or just after spot, so unlike
+/// [`Period`]s they are not measured from spot. [`FromStr`] accepts `ON`,
+/// `TN` and `SN` (also written `O/N`, `T/N`, `S/N`) and otherwise parses a
+/// [`Period`]:
+///
+/// ```rust
+/// use business_calendar::fx::FxTenor;
+/// use business_calendar::Period;
+///
+/// assert_eq!("TN".parse(), Ok(FxTenor::TomNext));
+/// assert_eq!("1M".parse(), Ok(FxTenor::FromSpot(Period::months(1))));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FxTenor {
+    /// Overnight: the first good value date after the trade date.
+    Overnight,
+    /// Tom-next: the second good value date after the trade date.
+    TomNext,
+    /// Spot-next: the first good value date after spot.
+    SpotNext,
+    /// A period measured from spot.
+    FromSpot(Period),
+}
+
+impl From<Period> for FxTenor {
+    #[inline]
+    fn from(period: Period) -> Self {
+        FxTenor::FromSpot(period)
+    }
+}
+
+impl FromStr for FxTenor {
+    type Err = ParsePeriodError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_uppercase().as_str() {
+            "ON" | "O/N" => Ok(FxTenor::Overnight),
+            "TN" | "T/N" => Ok(FxTenor::TomNext),
+            "SN" | "S/N" => Ok(FxTenor::SpotNext),
+            _ => s.parse().map(FxTenor::FromSpot),
+        }
+    }
+}
+
+/// A currency pair, e.g. EUR/USD.
+///
+/// As a [`Calendar`], a pair's business days are its good value dates:
+/// business days in both currencies and in USD.
+///
+/// ```rust
+/// use business_calendar::fx::{Currency, CurrencyPair};
+/// use business_calendar::Date;
+///
+/// let eur_usd = CurrencyPair::new(Currency::EUR, Currency::USD);
+/// // Wed 2 Jul 2025: T+2 is Independence Day, so spot rolls to Mon 7 Jul.
+/// assert_eq!(eur_usd.spot_date(Date::ymd(2025, 7, 2)), Date::ymd(2025, 7, 7));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CurrencyPair {
+    pub base: Currency,
+    pub quote: Currency,
+}
+
+impl CurrencyPair {
+    #[inline]
+    pub const fn new(base: Currency, quote: Currency) -> Self {
+        Self { base, quote }
+    }
+
+    /// Number of business days from trade date to spot.
+    pub fn spot_lag(&self) -> u32 {
+        match (self.base, self.quote) {
+            (Currency::USD, Currency::CAD | Currency::TRY)
+            | (Currency::CAD | Currency::TRY, Currency::USD) => 1,
+            _ => 2,
+        }
+    }
+
+    /// Returns `true` if `date` is a business day in every non-USD currency
+    /// of the pair, i.e. a valid intermediate day before spot.
+    fn is_non_usd_business_day(&self, date: Date) -> bool {
+        [self.base, self.quote]
+            .iter()
+            .filter(|&&ccy| ccy != Currency::USD)
+            .all(|ccy| ccy.is_business_day(date))
+    }
+
+    /// Spot value date for a trade on `trade_date`.
+    pub fn spot_date(&self, trade_date: Date) -> Date {
+        let mut d = trade_date;
+        for _ in 1..self.spot_lag() {
+            d = d.add_days(1);
+            while !self.is_non_usd_business_day(d) {
+                d = d.add_days(1);
+            }
+        }
+        d = d.add_days(1);
+        while !self.is_business_day(d) {
+            d = d.add_days(1);
+        }
+        d
+    }
+
+    /// Forward value date for a trade on `trade_date` with tenor `tenor`.
+    ///
+    /// [`Period`] tenors are measured from spot: day and week tenors roll
+    /// Following; month and year tenors roll Modified Following, and a spot
+    /// on the last good value date of its month maps to the last good value
+    /// date of the target month. `ON`, `TN` and `SN` are not periods from
+    /// spot; pass [`FxTenor::Overnight`], [`FxTenor::TomNext`] or
+    /// [`FxTenor::SpotNext`] (e.g. parsed with `"ON".parse::<FxTenor>()`)
+    /// for those.
+    pub fn forward_date(&self, trade_date: Date, tenor: impl Into<FxTenor>) -> Date {
+        let tenor = match tenor.into() {
+            FxTenor::Overnight => return self.next_business_day(trade_date),
+            FxTenor::TomNext => {
+                return self.next_business_day(self.next_business_day(trade_date));
+            }
+            FxTenor::SpotNext => return self.next_business_day(self.spot_date(trade_date)),
+            FxTenor::FromSpot(period) => period,
+        };
+        let spot = self.spot_date(trade_date);
+        match tenor.unit {
+            TimeUnit::Days | TimeUnit::Weeks => {
+                self.adjust(spot + tenor, BusinessDayConvention::Following)
+            }
+            TimeUnit::Months | TimeUnit::Years => {
+                self.advance_by(spot, tenor, BusinessDayConvention::ModifiedFollowing, true)
+            }
+        }
+    }
+}
+
+impl Calendar for CurrencyPair {
+    #[inline]
+    fn is_business_day(&self, date: Date) -> bool {
+        self.base.is_business_day(date)
+            && self.quote.is_business_day(date)
+            && Currency::USD.is_business_day(date)
+    }
+
+    #[inline]
+    fn is_always_closed_on(&self, weekday: Weekday) -> bool {
+        matches!(weekday, Weekday::Sat | Weekday::Sun)
+    }
+}
@@ -0,0 +1,209 @@
+//! Reliable horizons and what to do with queries beyond them.
+
+use std::fmt;
+
+use crate::calendar::{forward_calendar, Calendar};
+use crate::date::Date;
+use crate::explain::Explanation;
+use crate::rules::{NamedRule, Occurrence, YearRange};
+
+/// What the `try_` methods of [`Calendar`] do with dates outside
+/// [`Calendar::supported_years`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum OutOfRangePolicy {
+    /// Panic.
+    Panic,
+    /// Return an [`OutOfRange`] error.
+    #[default]
+    Error,
+    /// Answer from the rules anyway, flagged as [`Answer::Projected`].
+    Project,
+}
+
+/// An answer from a `try_` method of [`Calendar`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Answer<T> {
+    /// Every date involved is within the calendar's supported years.
+    Known(T),
+    /// Some date involved is beyond the supported years, so the answer is a
+    /// best-effort projection of the rules (e.g. missing one-off closures
+    /// nobody knows about yet).
+    Projected(T),
+}
+
+impl<T> Answer<T> {
+    /// The answer, projected or not.
+    #[inline]
+    pub fn value(self) -> T {
+        match self {
+            Answer::Known(value) | Answer::Projected(value) => value,
+        }
+    }
+
+    /// Returns `true` for [`Answer::Projected`].
+    #[inline]
+    pub fn is_projected(&self) -> bool {
+        matches!(self, Answer::Projected(_))
+    }
+
+    /// Applies `f` to the answer, keeping the flag.
+    #[inline]
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Answer<U> {
+        match self {
+            Answer::Known(value) => Answer::Known(f(value)),
+            Answer::Projected(value) => Answer::Projected(f(value)),
+        }
+    }
+}
+
+impl Answer<()> {
+    /// Projected if either `self` or `other` is.
+    pub(crate) fn and(self, other: Answer<()>) -> Answer<()> {
+        if self.is_projected() {
+            self
+        } else {
+            other
+        }
+    }
+}
+
+/// Error returned by the `try_` methods of [`Calendar`] for a date outside
+/// the calendar's supported years.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutOfRange {
+    date: Date,
+    supported: YearRange,
+}
+
+impl OutOfRange {
+    /// The offending date.
+    #[inline]
+    pub fn date(&self) -> Date {
+        self.date
+    }
+
+    /// The calendar's supported years.
+    #[inline]
+    pub fn supported_years(&self) -> YearRange {
+        self.supported
+    }
+}
+
+impl fmt::Display for OutOfRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} is outside the supported years {}..={}",
+            self.date.0, self.supported.start, self.supported.end
+        )
+    }
+}
+
+impl std::error::Error for OutOfRange {}
+
+/// Checks `date` against the supported years of `calendar`, applying its
+/// [`OutOfRangePolicy`].
+pub(crate) fn check<C: Calendar + ?Sized>(
+    calendar: &C,
+    date: Date,
+) -> Result<Answer<()>, OutOfRange> {
+    let supported = calendar.supported_years();
+    if supported.contains(date.year()) {
+        return Ok(Answer::Known(()));
+    }
+    let err = OutOfRange { date, supported };
+    match calendar.out_of_range_policy() {
+        OutOfRangePolicy::Panic => panic!("{}: {err}", calendar.name()),
+        OutOfRangePolicy::Error => Err(err),
+        OutOfRangePolicy::Project => Ok(Answer::Projected(())),
+    }
+}
+
+/// View of a calendar with a narrower reliable horizon and an
+/// [`OutOfRangePolicy`] for queries beyond it.
+///
+/// With [`OutOfRangePolicy::Panic`], every query about a date outside the
+/// horizon panics, not only the `try_` methods. With the other policies the
+/// infallible methods answer from the rules as usual.
+///
+/// Created by [`Calendar::with_horizon`].
+///
+/// ```rust
+/// use business_calendar::markets::us::nyse::US_NYSE;
+/// use business_calendar::{Answer, Calendar, Date, OutOfRangePolicy, YearRange};
+///
+/// let nyse = US_NYSE.with_horizon(YearRange::until(2030), OutOfRangePolicy::Project);
+/// let date = Date::ymd(2045, 7, 4);
+/// assert_eq!(nyse.try_is_business_day(date), Ok(Answer::Projected(false)));
+/// ```
+#[derive(Debug)]
+pub struct Horizon<'a, C: ?Sized> {
+    calendar: &'a C,
+    years: YearRange,
+    policy: OutOfRangePolicy,
+}
+
+impl<C: ?Sized> Clone for Horizon<'_, C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C: ?Sized> Copy for Horizon<'_, C> {}
+
+impl<'a, C: Calendar + ?Sized> Horizon<'a, C> {
+    /// `calendar` trusted only within `years` (and its own supported years),
+    /// handling other dates according to `policy`.
+    #[inline]
+    pub fn new(calendar: &'a C, years: YearRange, policy: OutOfRangePolicy) -> Self {
+        Self {
+            calendar,
+            years,
+            policy,
+        }
+    }
+
+    fn guard(&self, date: Date) {
+        if self.policy == OutOfRangePolicy::Panic {
+            let _ = check(self, date);
+        }
+    }
+}
+
+impl<C: Calendar + ?Sized> Calendar for Horizon<'_, C> {
+    forward_calendar!(self => self.calendar; name, metadata, weekend);
+
+    fn supported_years(&self) -> YearRange {
+        self.calendar.supported_years().intersection(self.years)
+    }
+
+    #[inline]
+    fn out_of_range_policy(&self) -> OutOfRangePolicy {
+        self.policy
+    }
+
+    fn is_business_day(&self, date: Date) -> bool {
+        self.guard(date);
+        self.calendar.is_business_day(date)
+    }
+
+    fn is_holiday(&self, date: Date) -> bool {
+        self.guard(date);
+        self.calendar.is_holiday(date)
+    }
+
+    fn matching_rules(&self, date: Date) -> Vec<NamedRule> {
+        self.guard(date);
+        self.calendar.matching_rules(date)
+    }
+
+    fn matching_occurrences(&self, date: Date) -> Vec<Occurrence> {
+        self.guard(date);
+        self.calendar.matching_occurrences(date)
+    }
+
+    fn explain(&self, date: Date) -> Explanation {
+        self.guard(date);
+        self.calendar.explain(date)
+    }
+}
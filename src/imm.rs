@@ -0,0 +1,99 @@
+//! IMM dates: the third Wednesday of a month, quarterly (Mar/Jun/Sep/Dec) on
+//! the main cycle, as used by futures and money-market contracts.
+
+use chrono::Weekday;
+
+use crate::calendar::{BusinessDayConvention, Calendar};
+use crate::date::Date;
+
+/// IMM month codes, January to December.
+const MONTH_CODES: [char; 12] = ['F', 'G', 'H', 'J', 'K', 'M', 'N', 'Q', 'U', 'V', 'X', 'Z'];
+
+/// Third Wednesday of `month` in `year`.
+#[inline]
+fn third_wednesday(year: i32, month: u32) -> Date {
+    Date::nth_weekday_of_month(year, month, Weekday::Wed, 3).expect("third Wednesday always exists")
+}
+
+#[inline]
+fn is_main_cycle_month(month: u32) -> bool {
+    month.is_multiple_of(3)
+}
+
+/// Returns `true` if `date` is an IMM date.
+///
+/// With `main_cycle`, only March, June, September and December qualify.
+pub fn is_imm_date(date: Date, main_cycle: bool) -> bool {
+    if date.weekday() != Weekday::Wed || !(15..=21).contains(&date.day()) {
+        return false;
+    }
+    !main_cycle || is_main_cycle_month(date.month())
+}
+
+/// First IMM date strictly after `date`.
+pub fn next_imm_date(date: Date, main_cycle: bool) -> Date {
+    let mut year = date.year();
+    let mut month = date.month();
+    loop {
+        if !main_cycle || is_main_cycle_month(month) {
+            let candidate = third_wednesday(year, month);
+            if candidate > date {
+                return candidate;
+            }
+        }
+        month += 1;
+        if month > 12 {
+            month = 1;
+            year += 1;
+        }
+    }
+}
+
+/// First IMM date strictly after `date`, rolled to a business day of
+/// `calendar` with the Following convention.
+pub fn next_imm_date_adjusted<C: Calendar + ?Sized>(
+    date: Date,
+    main_cycle: bool,
+    calendar: &C,
+) -> Date {
+    calendar.adjust(
+        next_imm_date(date, main_cycle),
+        BusinessDayConvention::Following,
+    )
+}
+
+/// IMM code of `date` (e.g. `"H6"` for 18 March 2026), or `None` if `date`
+/// is not an IMM date.
+pub fn imm_code(date: Date) -> Option<String> {
+    if !is_imm_date(date, false) {
+        return None;
+    }
+    let month = MONTH_CODES[date.month() as usize - 1];
+    let digit = date.year().rem_euclid(10);
+    Some(format!("{month}{digit}"))
+}
+
+/// IMM date for `code` (e.g. `"H6"`), taking the first such date on or after
+/// `reference` (codes only carry the last digit of the year).
+///
+/// Returns `None` if `code` is not a valid IMM code.
+pub fn imm_date_from_code(code: &str, reference: Date) -> Option<Date> {
+    let mut chars = code.chars();
+    let (letter, digit) = match (chars.next(), chars.next(), chars.next()) {
+        (Some(letter), Some(digit), None) => (letter, digit),
+        _ => return None,
+    };
+    let month = MONTH_CODES
+        .iter()
+        .position(|&c| c == letter.to_ascii_uppercase())? as u32
+        + 1;
+    let digit = digit.to_digit(10)? as i32;
+
+    let decade = reference.year() - reference.year().rem_euclid(10);
+    let date = third_wednesday(decade + digit, month);
+    if date < reference {
+        Some(third_wednesday(decade + digit + 10, month))
+    } else {
+        Some(date)
+    }
+}
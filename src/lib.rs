@@ -11,13 +11,43 @@
 //! ```
 
 mod calendar;
+mod category;
+mod currency;
 mod date;
-mod rules;
-mod static_calendar;
+mod date_range;
+mod day_counter;
+mod explain;
+mod horizon;
+mod period;
+mod validate;
 
+pub mod cds;
+pub mod derived_calendar;
+pub mod diff;
+pub mod dsl;
 pub mod easter;
+pub mod fx;
+pub mod imm;
 pub mod markets;
+pub mod rules;
+pub mod static_calendar;
 pub mod utils;
 
-pub use crate::calendar::Calendar;
+pub use crate::calendar::{
+    BusinessDayConvention, Calendar, HOLIDAY_SEARCH_YEARS, UNNAMED_CALENDAR, UNNAMED_HOLIDAY,
+};
+pub use crate::category::{CategoryFilter, HolidayCategories, HolidayCategory};
 pub use crate::date::Date;
+pub use crate::date_range::{BusinessDays, DateRange, Holidays};
+pub use crate::day_counter::DayCounter;
+pub use crate::derived_calendar::DerivedCalendar;
+pub use crate::explain::{Explanation, Override};
+pub use crate::horizon::{Answer, Horizon, OutOfRange, OutOfRangePolicy};
+pub use crate::markets::AnyCalendar;
+pub use crate::period::{ParsePeriodError, Period, TimeUnit};
+pub use crate::rules::{
+    DateTable, FnRule, HolidayRule, HolidayRuleLike, NamedRule, Occurrence, RelativeCondition,
+    RuleEffect, RuleRef, YearCycle, YearRange,
+};
+pub use crate::static_calendar::StaticCalendar;
+pub use crate::validate::{Diagnostic, Problem};
@@ -0,0 +1,67 @@
+//! Euro-area calendars.
+
+use crate::calendar::{forward_calendar, Calendar};
+use crate::currency::Currency;
+use crate::date::Date;
+use crate::derived_calendar::DerivedCalendar;
+
+pub mod target;
+
+pub use target::EU_TARGET;
+
+// Euro-area market variants: the TARGET payment system.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EUMarket {
+    Target,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Europe {
+    pub market: EUMarket,
+}
+
+impl Europe {
+    #[inline]
+    pub const fn new(market: EUMarket) -> Self {
+        Self { market }
+    }
+
+    /// Rule-based calendar backing this market.
+    fn calendar(&self) -> &'static dyn Calendar {
+        match self.market {
+            EUMarket::Target => &EU_TARGET,
+        }
+    }
+
+    /// This market's rules as known on `knowledge_date`; see
+    /// [`DerivedCalendar::as_of`].
+    pub fn as_of(&self, knowledge_date: Date) -> DerivedCalendar {
+        match self.market {
+            EUMarket::Target => EU_TARGET.as_of(knowledge_date),
+        }
+    }
+}
+
+impl Calendar for Europe {
+    forward_calendar!(self => self.calendar(); name, horizon, weekend, rules, navigation, fallible);
+
+    fn code(&self) -> &str {
+        match self.market {
+            EUMarket::Target => "EU-TARGET",
+        }
+    }
+
+    /// TARGET serves the whole euro area rather than one country.
+    fn country_code(&self) -> Option<&str> {
+        None
+    }
+
+    fn settlement_currency(&self) -> Option<Currency> {
+        Some(Currency::EUR)
+    }
+
+    /// TARGET runs on Central European Time, that of the ECB in Frankfurt.
+    fn time_zone(&self) -> Option<&str> {
+        Some("Europe/Berlin")
+    }
+}
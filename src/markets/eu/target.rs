@@ -0,0 +1,2 @@
+// Rules are maintained in `data/eu/target.toml`; see `data/README.md`.
+include!(concat!(env!("OUT_DIR"), "/eu/target.rs"));
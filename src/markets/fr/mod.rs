@@ -1,5 +1,6 @@
-use crate::calendar::Calendar;
+use crate::calendar::{forward_calendar, Calendar};
 use crate::date::Date;
+use crate::derived_calendar::DerivedCalendar;
 
 pub mod exchange;
 pub mod settlement;
@@ -8,29 +9,63 @@ use exchange::FR_EXCHANGE;
 use settlement::FR_SETTLEMENT;
 
 // France market variants (simplified QuantLib-style): Settlement and Exchange.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum FRMarket {
     Settlement,
     Exchange,
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct France {
     pub market: FRMarket,
 }
 
 impl France {
     #[inline]
-    pub fn new(market: FRMarket) -> Self {
+    pub const fn new(market: FRMarket) -> Self {
         Self { market }
     }
+
+    /// Rule-based calendar backing this market.
+    fn calendar(&self) -> &'static dyn Calendar {
+        match self.market {
+            FRMarket::Settlement => &FR_SETTLEMENT,
+            FRMarket::Exchange => &FR_EXCHANGE,
+        }
+    }
+
+    /// This market's rules as known on `knowledge_date`; see
+    /// [`DerivedCalendar::as_of`].
+    pub fn as_of(&self, knowledge_date: Date) -> DerivedCalendar {
+        match self.market {
+            FRMarket::Settlement => FR_SETTLEMENT.as_of(knowledge_date),
+            FRMarket::Exchange => FR_EXCHANGE.as_of(knowledge_date),
+        }
+    }
 }
 
 impl Calendar for France {
-    fn is_business_day(&self, date: Date) -> bool {
+    forward_calendar!(self => self.calendar(); name, horizon, weekend, rules, navigation, fallible);
+
+    fn code(&self) -> &str {
         match self.market {
-            FRMarket::Settlement => FR_SETTLEMENT.is_business_day(date),
-            FRMarket::Exchange => FR_EXCHANGE.is_business_day(date),
+            FRMarket::Settlement => "FR-SETTLEMENT",
+            FRMarket::Exchange => "FR-EXCHANGE",
         }
     }
+
+    fn country_code(&self) -> Option<&str> {
+        Some("FR")
+    }
+
+    fn mic(&self) -> Option<&str> {
+        match self.market {
+            FRMarket::Settlement => None,
+            FRMarket::Exchange => Some("XPAR"),
+        }
+    }
+
+    fn time_zone(&self) -> Option<&str> {
+        Some("Europe/Paris")
+    }
 }
@@ -1,6 +1,137 @@
 //! Market-specific calendar modules (US, EU, Asia, UK, ...).
 
+use crate::calendar::{forward_calendar, Calendar};
+use crate::date::Date;
+use crate::derived_calendar::DerivedCalendar;
+
 pub mod asia;
+pub mod eu;
 pub mod fr;
 pub mod uk;
 pub mod us;
+
+use eu::{EUMarket, Europe};
+use fr::{FRMarket, France};
+use uk::{UKMarket, UnitedKingdom};
+use us::{USMarket, UnitedStates};
+
+/// Any calendar shipped with this crate, dispatched statically.
+///
+/// Unlike `Box<dyn Calendar>`, this is `Copy`, comparable and hashable, so
+/// it can be stored in configs and used as a map key.
+///
+/// ```rust
+/// use business_calendar::markets::us::USMarket;
+/// use business_calendar::{AnyCalendar, Calendar, Date};
+///
+/// let cal = AnyCalendar::from(USMarket::NYSE);
+/// assert_eq!(cal.code(), "US-NYSE");
+/// assert!(!cal.is_business_day(Date::ymd(2025, 7, 4)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AnyCalendar {
+    UnitedStates(UnitedStates),
+    UnitedKingdom(UnitedKingdom),
+    France(France),
+    Europe(Europe),
+}
+
+impl AnyCalendar {
+    /// Every shipped market calendar.
+    pub const ALL: [AnyCalendar; 13] = [
+        AnyCalendar::UnitedStates(UnitedStates::new(USMarket::Settlement)),
+        AnyCalendar::UnitedStates(UnitedStates::new(USMarket::LiborImpact)),
+        AnyCalendar::UnitedStates(UnitedStates::new(USMarket::NYSE)),
+        AnyCalendar::UnitedStates(UnitedStates::new(USMarket::GovernmentBond)),
+        AnyCalendar::UnitedStates(UnitedStates::new(USMarket::SOFR)),
+        AnyCalendar::UnitedStates(UnitedStates::new(USMarket::NERC)),
+        AnyCalendar::UnitedStates(UnitedStates::new(USMarket::FederalReserve)),
+        AnyCalendar::UnitedKingdom(UnitedKingdom::new(UKMarket::Settlement)),
+        AnyCalendar::UnitedKingdom(UnitedKingdom::new(UKMarket::Exchange)),
+        AnyCalendar::UnitedKingdom(UnitedKingdom::new(UKMarket::Metals)),
+        AnyCalendar::France(France::new(FRMarket::Settlement)),
+        AnyCalendar::France(France::new(FRMarket::Exchange)),
+        AnyCalendar::Europe(Europe::new(EUMarket::Target)),
+    ];
+
+    /// The market calendar inside.
+    fn calendar(&self) -> &dyn Calendar {
+        match self {
+            AnyCalendar::UnitedStates(cal) => cal,
+            AnyCalendar::UnitedKingdom(cal) => cal,
+            AnyCalendar::France(cal) => cal,
+            AnyCalendar::Europe(cal) => cal,
+        }
+    }
+
+    /// This calendar's rules as known on `knowledge_date`; see
+    /// [`DerivedCalendar::as_of`].
+    pub fn as_of(&self, knowledge_date: Date) -> DerivedCalendar {
+        match self {
+            AnyCalendar::UnitedStates(cal) => cal.as_of(knowledge_date),
+            AnyCalendar::UnitedKingdom(cal) => cal.as_of(knowledge_date),
+            AnyCalendar::France(cal) => cal.as_of(knowledge_date),
+            AnyCalendar::Europe(cal) => cal.as_of(knowledge_date),
+        }
+    }
+}
+
+impl From<UnitedStates> for AnyCalendar {
+    #[inline]
+    fn from(calendar: UnitedStates) -> Self {
+        AnyCalendar::UnitedStates(calendar)
+    }
+}
+
+impl From<UnitedKingdom> for AnyCalendar {
+    #[inline]
+    fn from(calendar: UnitedKingdom) -> Self {
+        AnyCalendar::UnitedKingdom(calendar)
+    }
+}
+
+impl From<France> for AnyCalendar {
+    #[inline]
+    fn from(calendar: France) -> Self {
+        AnyCalendar::France(calendar)
+    }
+}
+
+impl From<Europe> for AnyCalendar {
+    #[inline]
+    fn from(calendar: Europe) -> Self {
+        AnyCalendar::Europe(calendar)
+    }
+}
+
+impl From<USMarket> for AnyCalendar {
+    #[inline]
+    fn from(market: USMarket) -> Self {
+        AnyCalendar::UnitedStates(UnitedStates::new(market))
+    }
+}
+
+impl From<UKMarket> for AnyCalendar {
+    #[inline]
+    fn from(market: UKMarket) -> Self {
+        AnyCalendar::UnitedKingdom(UnitedKingdom::new(market))
+    }
+}
+
+impl From<FRMarket> for AnyCalendar {
+    #[inline]
+    fn from(market: FRMarket) -> Self {
+        AnyCalendar::France(France::new(market))
+    }
+}
+
+impl From<EUMarket> for AnyCalendar {
+    #[inline]
+    fn from(market: EUMarket) -> Self {
+        AnyCalendar::Europe(Europe::new(market))
+    }
+}
+
+impl Calendar for AnyCalendar {
+    forward_calendar!(self => self.calendar(); all);
+}
@@ -0,0 +1,2 @@
+// Rules are maintained in `data/uk/metals.toml`; see `data/README.md`.
+include!(concat!(env!("OUT_DIR"), "/uk/metals.rs"));
@@ -1,43 +1,88 @@
 //! UK market calendars (LSE, UK settlement, etc.).
 
-use crate::calendar::Calendar;
+use crate::calendar::{forward_calendar, Calendar};
+use crate::currency::Currency;
 use crate::date::Date;
+use crate::derived_calendar::DerivedCalendar;
 
-mod settlement;
 mod exchange;
+mod metals;
+mod settlement;
 
-pub use settlement::UK_SETTLEMENT;
 pub use exchange::UK_EXCHANGE;
+pub use metals::UK_METALS;
+pub use settlement::UK_SETTLEMENT;
 
 // United Kingdom market variants: Settlement, Exchange (LSE-style), and Metals.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum UKMarket {
     Settlement,
     Exchange,
     Metals,
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct UnitedKingdom {
     pub market: UKMarket,
 }
 
 impl UnitedKingdom {
     #[inline]
-    pub fn new(market: UKMarket) -> Self {
+    pub const fn new(market: UKMarket) -> Self {
         Self { market }
     }
-}
 
+    /// Rule-based calendar backing this market.
+    fn calendar(&self) -> &'static dyn Calendar {
+        match self.market {
+            UKMarket::Settlement => &UK_SETTLEMENT,
+            UKMarket::Exchange => &UK_EXCHANGE,
+            UKMarket::Metals => &UK_METALS,
+        }
+    }
+
+    /// This market's rules as known on `knowledge_date`; see
+    /// [`DerivedCalendar::as_of`].
+    pub fn as_of(&self, knowledge_date: Date) -> DerivedCalendar {
+        match self.market {
+            UKMarket::Settlement => UK_SETTLEMENT.as_of(knowledge_date),
+            UKMarket::Exchange => UK_EXCHANGE.as_of(knowledge_date),
+            UKMarket::Metals => UK_METALS.clone().as_of(knowledge_date),
+        }
+    }
+}
 
 impl Calendar for UnitedKingdom {
-    fn is_business_day(&self, date: Date) -> bool {
+    forward_calendar!(self => self.calendar(); name, horizon, weekend, rules, navigation, fallible);
+
+    fn code(&self) -> &str {
+        match self.market {
+            UKMarket::Settlement => "UK-SETTLEMENT",
+            UKMarket::Exchange => "UK-EXCHANGE",
+            UKMarket::Metals => "UK-METALS",
+        }
+    }
+
+    fn country_code(&self) -> Option<&str> {
+        Some("GB")
+    }
+
+    fn mic(&self) -> Option<&str> {
         match self.market {
-            UKMarket::Settlement => UK_SETTLEMENT.is_business_day(date),
-            UKMarket::Exchange => UK_EXCHANGE.is_business_day(date),
-            // In QuantLib, the UK Metals calendar shares the same rules as the
-            // ExchangeImpl. We mirror that here by reusing `UK_EXCHANGE`.
-            UKMarket::Metals => UK_EXCHANGE.is_business_day(date),
+            UKMarket::Settlement => None,
+            UKMarket::Exchange => Some("XLON"),
+            UKMarket::Metals => Some("XLME"),
         }
     }
+
+    fn settlement_currency(&self) -> Option<Currency> {
+        match self.market {
+            UKMarket::Metals => Some(Currency::USD),
+            _ => Some(Currency::GBP),
+        }
+    }
+
+    fn time_zone(&self) -> Option<&str> {
+        Some("Europe/London")
+    }
 }
@@ -0,0 +1,2 @@
+// Rules are maintained in `data/us/libor_impact.toml`; see `data/README.md`.
+include!(concat!(env!("OUT_DIR"), "/us/libor_impact.rs"));
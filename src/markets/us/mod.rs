@@ -1,12 +1,12 @@
 //! United States market calendars (Settlement, NYSE, Fed, etc.).
 
-use chrono::Weekday;
-
-use crate::calendar::Calendar;
+use crate::calendar::{forward_calendar, Calendar};
 use crate::date::Date;
+use crate::derived_calendar::DerivedCalendar;
 
 pub mod fed;
 pub mod gov_bond;
+pub mod libor_impact;
 pub mod nerc;
 pub mod nyse;
 pub mod settlement;
@@ -14,6 +14,7 @@ pub mod sofr;
 
 use fed::US_FED;
 use gov_bond::US_GOVERNMENT_BOND;
+use libor_impact::US_LIBOR_IMPACT;
 use nerc::US_NERC;
 use nyse::US_NYSE;
 use settlement::US_SETTLEMENT;
@@ -24,7 +25,7 @@ use sofr::US_SOFR;
 //
 
 /// US market variants supported by `UnitedStates`.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum USMarket {
     Settlement,
     LiborImpact,
@@ -36,54 +37,73 @@ pub enum USMarket {
 }
 
 /// United States calendar wrapper.
-/// Uses underlying `StaticCalendar`s and adds a bit of market-specific logic.
-#[derive(Debug, Clone, Copy)]
+/// Dispatches to the rule-based calendar of each market.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct UnitedStates {
     pub market: USMarket,
 }
 
 impl UnitedStates {
     #[inline]
-    pub fn new(market: USMarket) -> Self {
+    pub const fn new(market: USMarket) -> Self {
         Self { market }
     }
-}
 
-impl Calendar for UnitedStates {
-    fn is_business_day(&self, date: Date) -> bool {
+    /// Rule-based calendar backing this market.
+    fn calendar(&self) -> &'static dyn Calendar {
         match self.market {
-            USMarket::Settlement => US_SETTLEMENT.is_business_day(date),
-
-            USMarket::LiborImpact => {
-                // LiborImpact: same as Settlement, but since 2015
-                // Independence Day only impacts Libor if the actual
-                // July 4 falls on a weekday.
-                let m = date.month();
-                let d = date.day();
-                let y = date.year();
-                let w = date.weekday();
-
-                // if it's the Friday/Monday *around* July 4 and >=2015,
-                // treat as business day even if Settlement says holiday.
-                if y >= 2015
-                    && m == 7
-                    && ((d == 5 && w == Weekday::Mon) || (d == 3 && w == Weekday::Fri))
-                {
-                    return true;
-                }
-
-                US_SETTLEMENT.is_business_day(date)
-            }
+            USMarket::Settlement => &US_SETTLEMENT,
+            USMarket::LiborImpact => &US_LIBOR_IMPACT,
+            USMarket::NYSE => &US_NYSE,
+            USMarket::GovernmentBond => &US_GOVERNMENT_BOND,
+            USMarket::SOFR => &US_SOFR,
+            USMarket::NERC => &US_NERC,
+            USMarket::FederalReserve => &US_FED,
+        }
+    }
 
-            USMarket::NYSE => US_NYSE.is_business_day(date),
+    /// This market's rules as known on `knowledge_date`; see
+    /// [`DerivedCalendar::as_of`].
+    pub fn as_of(&self, knowledge_date: Date) -> DerivedCalendar {
+        match self.market {
+            USMarket::Settlement => US_SETTLEMENT.as_of(knowledge_date),
+            USMarket::LiborImpact => US_LIBOR_IMPACT.clone().as_of(knowledge_date),
+            USMarket::NYSE => US_NYSE.as_of(knowledge_date),
+            USMarket::GovernmentBond => US_GOVERNMENT_BOND.as_of(knowledge_date),
+            USMarket::SOFR => US_SOFR.clone().as_of(knowledge_date),
+            USMarket::NERC => US_NERC.as_of(knowledge_date),
+            USMarket::FederalReserve => US_FED.as_of(knowledge_date),
+        }
+    }
+}
 
-            USMarket::GovernmentBond => US_GOVERNMENT_BOND.is_business_day(date),
+impl Calendar for UnitedStates {
+    forward_calendar!(self => self.calendar(); name, horizon, weekend, rules, navigation, fallible);
 
-            USMarket::SOFR => US_SOFR.is_business_day(date),
+    fn code(&self) -> &str {
+        match self.market {
+            USMarket::Settlement => "US-SETTLEMENT",
+            USMarket::LiborImpact => "US-LIBOR-IMPACT",
+            USMarket::NYSE => "US-NYSE",
+            USMarket::GovernmentBond => "US-GOVERNMENT-BOND",
+            USMarket::SOFR => "US-SOFR",
+            USMarket::NERC => "US-NERC",
+            USMarket::FederalReserve => "US-FEDERAL-RESERVE",
+        }
+    }
 
-            USMarket::NERC => US_NERC.is_business_day(date),
+    fn country_code(&self) -> Option<&str> {
+        Some("US")
+    }
 
-            USMarket::FederalReserve => US_FED.is_business_day(date),
+    fn mic(&self) -> Option<&str> {
+        match self.market {
+            USMarket::NYSE => Some("XNYS"),
+            _ => None,
         }
     }
+
+    fn time_zone(&self) -> Option<&str> {
+        Some("America/New_York")
+    }
 }
@@ -1,99 +1,2 @@
-use chrono::Weekday;
-
-use crate::rules::{HolidayRule, YearRange};
-use crate::static_calendar::StaticCalendar;
-
-// Settlement: generic US business calendar (bank/settlement days).
-//
-// Each rule below corresponds to a specific US holiday:
-// - 01-01  : New Year's Day (weekend adjusted)
-// - Jan 3rd Mon from 1983 : Martin Luther King Jr. Day
-// - Feb 3rd Mon from 1971 : Washington's Birthday / Presidents' Day
-// - May last Mon from 1971: Memorial Day
-// - 06-19 from 2022       : Juneteenth National Independence Day (weekend adjusted)
-// - 07-04                 : Independence Day (weekend adjusted)
-// - Sep 1st Mon           : Labor Day
-// - Oct 2nd Mon           : Columbus Day / Indigenous Peoples' Day
-// - 11-11                 : Veterans Day (weekend adjusted)
-// - Nov 4th Thu           : Thanksgiving Day
-// - 12-25                 : Christmas Day (weekend adjusted)
-static US_SETTLEMENT_RULES: &[HolidayRule] = &[
-    // New Year's Day
-    HolidayRule::WeekendAdjustedFixed {
-        month: 1,
-        day: 1,
-        years: YearRange::always(),
-    },
-    // Martin Luther King Jr. Day (3rd Monday in January, from 1983)
-    HolidayRule::NthWeekday {
-        month: 1,
-        weekday: Weekday::Mon,
-        nth: 3,
-        years: YearRange::from(1983),
-    },
-    // Washington's Birthday / Presidents' Day (3rd Monday in February, from 1971)
-    HolidayRule::NthWeekday {
-        month: 2,
-        weekday: Weekday::Mon,
-        nth: 3,
-        years: YearRange::from(1971),
-    },
-    // Memorial Day (last Monday in May, from 1971)
-    HolidayRule::NthWeekday {
-        month: 5,
-        weekday: Weekday::Mon,
-        nth: -1,
-        years: YearRange::from(1971),
-    },
-    // Juneteenth National Independence Day (from 2022, weekend adjusted)
-    HolidayRule::WeekendAdjustedFixed {
-        month: 6,
-        day: 19,
-        years: YearRange::from(2022),
-    },
-    // Independence Day (weekend adjusted)
-    HolidayRule::WeekendAdjustedFixed {
-        month: 7,
-        day: 4,
-        years: YearRange::always(),
-    },
-    // Labor Day (1st Monday in September)
-    HolidayRule::NthWeekday {
-        month: 9,
-        weekday: Weekday::Mon,
-        nth: 1,
-        years: YearRange::always(),
-    },
-    // Columbus Day / Indigenous Peoples' Day (2nd Monday in October)
-    HolidayRule::NthWeekday {
-        month: 10,
-        weekday: Weekday::Mon,
-        nth: 2,
-        years: YearRange::always(),
-    },
-    // Veterans Day (weekend adjusted)
-    HolidayRule::WeekendAdjustedFixed {
-        month: 11,
-        day: 11,
-        years: YearRange::always(),
-    },
-    // Thanksgiving Day (4th Thursday in November)
-    HolidayRule::NthWeekday {
-        month: 11,
-        weekday: Weekday::Thu,
-        nth: 4,
-        years: YearRange::always(),
-    },
-    // Christmas Day (weekend adjusted)
-    HolidayRule::WeekendAdjustedFixed {
-        month: 12,
-        day: 25,
-        years: YearRange::always(),
-    },
-];
-
-pub static US_SETTLEMENT: StaticCalendar = StaticCalendar {
-    name: "US Settlement",
-    weekend: [Weekday::Sat, Weekday::Sun],
-    rules: US_SETTLEMENT_RULES,
-};
+// Rules are maintained in `data/us/settlement.toml`; see `data/README.md`.
+include!(concat!(env!("OUT_DIR"), "/us/settlement.rs"));
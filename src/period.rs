@@ -0,0 +1,190 @@
+//! Periods (tenors) such as `3M` or `1Y6M`, and date arithmetic with them.
+
+use std::fmt;
+use std::ops::{Add, Neg, Sub};
+use std::str::FromStr;
+
+use crate::date::Date;
+
+/// Unit of a [`Period`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TimeUnit {
+    Days,
+    Weeks,
+    Months,
+    Years,
+}
+
+/// A length of time expressed as a number of days, weeks, months or years.
+///
+/// ```rust
+/// use business_calendar::{Date, Period};
+///
+/// let p: Period = "1Y6M".parse().unwrap();
+/// assert_eq!(p, Period::months(18));
+/// assert_eq!(Date::ymd(2024, 8, 31) + p, Date::ymd(2026, 2, 28));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Period {
+    pub length: i32,
+    pub unit: TimeUnit,
+}
+
+impl Period {
+    #[inline]
+    pub const fn new(length: i32, unit: TimeUnit) -> Self {
+        Self { length, unit }
+    }
+
+    #[inline]
+    pub const fn days(n: i32) -> Self {
+        Self::new(n, TimeUnit::Days)
+    }
+
+    #[inline]
+    pub const fn weeks(n: i32) -> Self {
+        Self::new(n, TimeUnit::Weeks)
+    }
+
+    #[inline]
+    pub const fn months(n: i32) -> Self {
+        Self::new(n, TimeUnit::Months)
+    }
+
+    #[inline]
+    pub const fn years(n: i32) -> Self {
+        Self::new(n, TimeUnit::Years)
+    }
+}
+
+impl Neg for Period {
+    type Output = Period;
+
+    #[inline]
+    fn neg(self) -> Period {
+        Period::new(-self.length, self.unit)
+    }
+}
+
+impl fmt::Display for Period {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let unit = match self.unit {
+            TimeUnit::Days => 'D',
+            TimeUnit::Weeks => 'W',
+            TimeUnit::Months => 'M',
+            TimeUnit::Years => 'Y',
+        };
+        write!(f, "{}{}", self.length, unit)
+    }
+}
+
+/// Error returned when a string is not a valid tenor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsePeriodError {
+    input: String,
+}
+
+impl fmt::Display for ParsePeriodError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid period: {:?}", self.input)
+    }
+}
+
+impl std::error::Error for ParsePeriodError {}
+
+impl FromStr for Period {
+    type Err = ParsePeriodError;
+
+    /// Parses tenors such as `"3M"`, `"2W"`, `"1Y6M"` or `"10D"` (case-insensitive).
+    ///
+    /// Compound tenors are normalised: years and months into months, weeks
+    /// and days into days. Mixing the two families (e.g. `"1M2D"`) is an error.
+    ///
+    /// The money-market tenors `ON`, `TN` and `SN` are parsed as the number
+    /// of days from trade date to the end of the deposit for a T+2 spot:
+    /// one, two and three days respectively. They are not periods from spot:
+    /// for FX value dates parse an [`FxTenor`](crate::fx::FxTenor) instead.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParsePeriodError {
+            input: s.to_string(),
+        };
+        let upper = s.trim().to_ascii_uppercase();
+
+        match upper.as_str() {
+            "ON" => return Ok(Period::days(1)),
+            "TN" => return Ok(Period::days(2)),
+            "SN" => return Ok(Period::days(3)),
+            "" => return Err(err()),
+            _ => {}
+        }
+
+        let (sign, body) = match upper.strip_prefix('-') {
+            Some(rest) => (-1, rest),
+            None => (1, upper.as_str()),
+        };
+
+        let mut months: Option<i32> = None;
+        let mut days: Option<i32> = None;
+        let mut units = String::new();
+        let mut number = String::new();
+        for c in body.chars() {
+            if c.is_ascii_digit() {
+                number.push(c);
+                continue;
+            }
+            if units.contains(c) {
+                return Err(err());
+            }
+            units.push(c);
+            let n: i32 = number.parse().map_err(|_| err())?;
+            number.clear();
+            let (total, factor) = match c {
+                'Y' => (&mut months, 12),
+                'M' => (&mut months, 1),
+                'W' => (&mut days, 7),
+                'D' => (&mut days, 1),
+                _ => return Err(err()),
+            };
+            let n = n.checked_mul(factor).ok_or_else(err)?;
+            *total = Some(total.unwrap_or(0).checked_add(n).ok_or_else(err)?);
+        }
+        if !number.is_empty() {
+            return Err(err());
+        }
+
+        match (months, days) {
+            (Some(m), None) if m % 12 == 0 && !body.contains('M') => {
+                Ok(Period::years(sign * m / 12))
+            }
+            (Some(m), None) => Ok(Period::months(sign * m)),
+            (None, Some(d)) if d % 7 == 0 && !body.contains('D') => Ok(Period::weeks(sign * d / 7)),
+            (None, Some(d)) => Ok(Period::days(sign * d)),
+            _ => Err(err()),
+        }
+    }
+}
+
+impl Add<Period> for Date {
+    type Output = Date;
+
+    /// Calendar (not business-day) arithmetic; months and years clamp to the
+    /// end of the target month.
+    #[inline]
+    fn add(self, period: Period) -> Date {
+        match period.unit {
+            TimeUnit::Days => self.add_days(period.length),
+            TimeUnit::Weeks => self.add_days(7 * period.length),
+            TimeUnit::Months => self.add_months(period.length),
+            TimeUnit::Years => self.add_months(12 * period.length),
+        }
+    }
+}
+
+impl Sub<Period> for Date {
+    type Output = Date;
+
+    #[inline]
+    fn sub(self, period: Period) -> Date {
+        self + (-period)
+    }
+}
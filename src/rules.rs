@@ -1,10 +1,16 @@
-use chrono::Weekday;
+//! Holiday rules: the built-in [`HolidayRule`] kinds, named rules and the
+//! [`HolidayRuleLike`] extension point for user-defined rules.
 
+use std::fmt;
+
+use chrono::{NaiveDate, Weekday};
+
+use crate::category::HolidayCategory;
 use crate::date::Date;
-use crate::easter::{orthodox_easter_monday, western_easter_monday};
+use crate::easter::{self, orthodox_easter_monday, western_easter_monday};
 
 /// Year range used for rules; inclusive [start, end].
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct YearRange {
     pub start: i32,
     pub end: i32,
@@ -44,6 +50,37 @@ impl YearRange {
     pub fn contains(&self, y: i32) -> bool {
         y >= self.start && y <= self.end
     }
+
+    /// Years in both `self` and `other`.
+    #[inline]
+    pub(crate) fn intersection(&self, other: YearRange) -> YearRange {
+        YearRange::between(self.start.max(other.start), self.end.min(other.end))
+    }
+}
+
+/// Every `modulus`th year: years with `year % modulus == remainder`
+/// (Euclidean, so negative years work too).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct YearCycle {
+    pub modulus: i32,
+    pub remainder: i32,
+}
+
+impl YearCycle {
+    /// # Panics
+    ///
+    /// If `modulus` is not positive (a compile error in `const` context).
+    #[inline]
+    pub const fn new(modulus: i32, remainder: i32) -> Self {
+        assert!(modulus > 0, "year cycle modulus must be positive");
+        Self { modulus, remainder }
+    }
+
+    /// Returns `true` if `y` is in this cycle; never for a zero modulus.
+    #[inline]
+    pub fn contains(&self, y: i32) -> bool {
+        y.checked_rem_euclid(self.modulus) == Some(self.remainder)
+    }
 }
 
 /// Static, data-driven holiday rules.
@@ -80,16 +117,324 @@ pub enum HolidayRule {
         years: YearRange,
     },
 
+    /// First `weekday` on or after `month`/`day`, shifted by `offset` days
+    /// (e.g. US Election Day: Tuesday on or after 2 November).
+    WeekdayOnOrAfter {
+        month: u32,
+        day: u32,
+        weekday: Weekday,
+        offset: i32,
+        years: YearRange,
+    },
+
+    /// Last `weekday` on or before `month`/`day`, shifted by `offset` days
+    /// (e.g. Victoria Day: Monday on or before 24 May).
+    WeekdayOnOrBefore {
+        month: u32,
+        day: u32,
+        weekday: Weekday,
+        offset: i32,
+        years: YearRange,
+    },
+
+    /// First `weekday` strictly after `month`/`day`, shifted by `offset` days
+    /// (e.g. Midsummer Eve: the day before the first Saturday after 19 June).
+    WeekdayAfter {
+        month: u32,
+        day: u32,
+        weekday: Weekday,
+        offset: i32,
+        years: YearRange,
+    },
+
+    /// `offset` days from another holiday of the same calendar, kept only if
+    /// `condition` holds (e.g. the day after Thanksgiving, bridge days).
+    ///
+    /// Resolved by [`StaticCalendar`](crate::StaticCalendar) against the base
+    /// holiday's observed date in the same year; on its own the rule never
+    /// matches.
+    Relative {
+        base: RuleRef,
+        offset: i32,
+        condition: RelativeCondition,
+        years: YearRange,
+    },
+
     /// One-off holiday on a specific date.
     OneOff { year: i32, month: u32, day: u32 },
+
+    /// Holidays on every date of a sorted table; see [`DateTable`].
+    Dates(DateTable),
+
+    /// User-supplied rule; see [`HolidayRuleLike`].
+    Custom(&'static dyn HolidayRuleLike),
+}
+
+/// Sorted table of dates packed as `YYYYMMDD` integers, searched with a
+/// binary search; for long lists of specific dates (lunar holidays,
+/// historical closures) that would otherwise be many `OneOff` rules.
+///
+/// ```rust
+/// use business_calendar::rules::DateTable;
+/// use business_calendar::Date;
+///
+/// static SPRING_FESTIVAL: DateTable = DateTable::new(&[20250128, 20250129, 20250130, 20260216]);
+/// assert!(SPRING_FESTIVAL.contains(Date::ymd(2025, 1, 29)));
+/// assert_eq!(SPRING_FESTIVAL.in_year(2025).count(), 3);
+/// ```
+///
+/// Unsorted, duplicated or invalid dates are rejected when the table is
+/// built, at compile time for `const` and `static` tables:
+///
+/// ```compile_fail
+/// use business_calendar::rules::DateTable;
+///
+/// static BAD: DateTable = DateTable::new(&[20250130, 20250129]);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct DateTable {
+    dates: &'static [u32],
+}
+
+impl DateTable {
+    /// Builds a table from strictly ascending, valid `YYYYMMDD` dates.
+    ///
+    /// # Panics
+    ///
+    /// If a date is invalid or the dates are not strictly ascending.
+    pub const fn new(dates: &'static [u32]) -> Self {
+        let mut i = 0;
+        while i < dates.len() {
+            let (year, month, day) = unpack(dates[i]);
+            if !crate::date::is_valid_date(year, month, day) {
+                panic!("DateTable: invalid YYYYMMDD date");
+            }
+            if i > 0 && dates[i - 1] >= dates[i] {
+                panic!("DateTable: dates must be strictly ascending");
+            }
+            i += 1;
+        }
+        Self { dates }
+    }
+
+    /// Number of dates in the table.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.dates.len()
+    }
+
+    /// Returns `true` if the table has no dates.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.dates.is_empty()
+    }
+
+    /// Returns `true` if `date` is in the table.
+    #[inline]
+    pub fn contains(&self, date: Date) -> bool {
+        pack(date).is_some_and(|key| self.dates.binary_search(&key).is_ok())
+    }
+
+    /// Dates of the table in `year`, in ascending order.
+    pub fn in_year(&self, year: i32) -> impl DoubleEndedIterator<Item = Date> + '_ {
+        let (start, end) = if year < 0 {
+            (0, 0)
+        } else {
+            let base = year as u32 * 10_000;
+            (
+                self.dates.partition_point(|&d| d < base),
+                self.dates.partition_point(|&d| d < base + 10_000),
+            )
+        };
+        self.dates[start..end].iter().map(|&d| {
+            let (y, m, d) = unpack(d);
+            Date::ymd(y, m, d)
+        })
+    }
+}
+
+impl HolidayRuleLike for DateTable {
+    #[inline]
+    fn is_holiday(&self, date: Date) -> bool {
+        self.contains(date)
+    }
+
+    fn holidays_in_year(&self, year: i32) -> Vec<Date> {
+        self.in_year(year).collect()
+    }
+}
+
+const fn unpack(packed: u32) -> (i32, u32, u32) {
+    ((packed / 10_000) as i32, packed / 100 % 100, packed % 100)
+}
+
+fn pack(date: Date) -> Option<u32> {
+    let year = u32::try_from(date.year()).ok()?;
+    Some(year * 10_000 + date.month() * 100 + date.day())
+}
+
+/// A holiday rule that can be plugged into a [`StaticCalendar`](crate::StaticCalendar)
+/// through [`HolidayRule::Custom`].
+///
+/// ```rust
+/// use business_calendar::rules::{FnRule, HolidayRule, HolidayRuleLike, NamedRule};
+/// use business_calendar::{Calendar, Date, StaticCalendar};
+/// use chrono::Weekday;
+///
+/// // Every 13th of the month that falls on a Friday.
+/// static FRIDAY_13TH: FnRule = FnRule::new(|d| d.day() == 13 && d.weekday() == Weekday::Fri);
+/// static RULES: &[NamedRule] = &[NamedRule::new("Friday 13th", HolidayRule::Custom(&FRIDAY_13TH))];
+/// static CAL: StaticCalendar = StaticCalendar::new("Superstitious", [Weekday::Sat, Weekday::Sun], RULES);
+///
+/// assert!(!CAL.is_business_day(Date::ymd(2026, 2, 13)));
+/// assert_eq!(FRIDAY_13TH.holidays_in_year(2026).len(), 3);
+/// ```
+pub trait HolidayRuleLike: fmt::Debug + Send + Sync {
+    /// Returns `true` if this rule marks `date` as a holiday.
+    fn is_holiday(&self, date: Date) -> bool;
+
+    /// Holidays of this rule in `year`, in ascending order.
+    ///
+    /// The default implementation tests every day of the year; override it
+    /// when the dates can be computed directly.
+    fn holidays_in_year(&self, year: i32) -> Vec<Date> {
+        let (Some(first), Some(last)) = (
+            NaiveDate::from_ymd_opt(year, 1, 1),
+            NaiveDate::from_ymd_opt(year, 12, 31),
+        ) else {
+            return Vec::new();
+        };
+        first
+            .iter_days()
+            .take_while(|d| *d <= last)
+            .map(Date)
+            .filter(|&d| self.is_holiday(d))
+            .collect()
+    }
+}
+
+impl HolidayRuleLike for HolidayRule {
+    #[inline]
+    fn is_holiday(&self, date: Date) -> bool {
+        self.matches(date, date.year())
+    }
+
+    fn holidays_in_year(&self, year: i32) -> Vec<Date> {
+        match self {
+            HolidayRule::Dates(table) => table.holidays_in_year(year),
+            HolidayRule::Custom(rule) => rule.holidays_in_year(year),
+            _ => {
+                let mut dates: Vec<_> = (year - 1..=year + 1)
+                    .flat_map(|y| self.actual_date(y).into_iter().chain(self.observed_date(y)))
+                    .filter(|&date| date.year() == year && self.matches(date, year))
+                    .collect();
+                dates.sort();
+                dates.dedup();
+                dates
+            }
+        }
+    }
+}
+
+/// A [`HolidayRuleLike`] backed by a predicate, e.g. a closure.
+#[derive(Clone, Copy)]
+pub struct FnRule<F = fn(Date) -> bool>(pub F);
+
+impl<F> FnRule<F> {
+    #[inline]
+    pub const fn new(f: F) -> Self {
+        Self(f)
+    }
+}
+
+impl<F> fmt::Debug for FnRule<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("FnRule")
+    }
+}
+
+impl<F: Fn(Date) -> bool + Send + Sync> HolidayRuleLike for FnRule<F> {
+    #[inline]
+    fn is_holiday(&self, date: Date) -> bool {
+        (self.0)(date)
+    }
+}
+
+/// Reference from a [`HolidayRule::Relative`] rule to another rule of the
+/// same calendar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RuleRef {
+    /// The first rule with this name that is active in the year.
+    Name(&'static str),
+    /// The rule at this index in the calendar's rule table (for a
+    /// [`DerivedCalendar`](crate::DerivedCalendar), its base table).
+    Index(usize),
+}
+
+/// When a [`HolidayRule::Relative`] rule applies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RelativeCondition {
+    /// Always.
+    Always,
+    /// Only if the resulting date is not on one of the calendar's weekend
+    /// weekdays (working-day rules are not consulted).
+    Weekday,
+    /// Only if the base holiday is observed on this weekday (e.g. a Monday
+    /// bridge day before a Tuesday holiday).
+    BaseOn(Weekday),
+    /// Only if the base holiday is observed on another day than it falls
+    /// (e.g. moved off the weekend).
+    Moved,
+    /// Only if the days before and after the resulting date are both
+    /// holidays (e.g. Japan's citizens' holiday).
+    Sandwiched,
 }
 
 impl HolidayRule {
+    /// Years this rule applies to, or `None` for rules without a year range
+    /// (one-offs, date tables and custom rules).
+    pub const fn years(&self) -> Option<YearRange> {
+        match *self {
+            HolidayRule::Fixed { years, .. }
+            | HolidayRule::WeekendAdjustedFixed { years, .. }
+            | HolidayRule::NthWeekday { years, .. }
+            | HolidayRule::EasterOffset { years, .. }
+            | HolidayRule::WeekdayOnOrAfter { years, .. }
+            | HolidayRule::WeekdayOnOrBefore { years, .. }
+            | HolidayRule::WeekdayAfter { years, .. }
+            | HolidayRule::Relative { years, .. } => Some(years),
+            HolidayRule::OneOff { .. } | HolidayRule::Dates(_) | HolidayRule::Custom(_) => None,
+        }
+    }
+
+    /// This rule restricted to `years`.
+    ///
+    /// # Panics
+    ///
+    /// If the rule has no year range (see [`HolidayRule::years`]).
+    pub const fn with_years(mut self, range: YearRange) -> Self {
+        match &mut self {
+            HolidayRule::Fixed { years, .. }
+            | HolidayRule::WeekendAdjustedFixed { years, .. }
+            | HolidayRule::NthWeekday { years, .. }
+            | HolidayRule::EasterOffset { years, .. }
+            | HolidayRule::WeekdayOnOrAfter { years, .. }
+            | HolidayRule::WeekdayOnOrBefore { years, .. }
+            | HolidayRule::WeekdayAfter { years, .. }
+            | HolidayRule::Relative { years, .. } => *years = range,
+            HolidayRule::OneOff { .. } | HolidayRule::Dates(_) | HolidayRule::Custom(_) => {
+                panic!("rule has no year range")
+            }
+        }
+        self
+    }
+
     /// Returns `true` if this rule marks the given `date` as a holiday.
     ///
-    /// `year` is passed separately to avoid recomputing it for each rule.
+    /// `year` is passed separately to avoid recomputing it for each rule;
+    /// [`HolidayRuleLike::is_holiday`] computes it.
     #[inline]
-    pub fn is_holiday(&self, date: Date, year: i32) -> bool {
+    pub fn matches(&self, date: Date, year: i32) -> bool {
         match *self {
             HolidayRule::Fixed { month, day, years } => {
                 years.contains(year) && date.month() == month && date.day() == day
@@ -127,11 +472,9 @@ impl HolidayRule {
                     let idx = nth as i32 - 1;
                     day > idx * 7 && day <= (idx + 1) * 7
                 } else {
-                    // From end of month: approximate by week index from end.
-                    // This keeps the logic simple; more exact variants can be
-                    // added later if needed.
+                    // From end of month: last Monday is within the last 7 days, etc.
                     let nth_abs = -nth as i32;
-                    let ordinal_from_end = 31 - day; // upper bound; fine for rule buckets
+                    let ordinal_from_end = date.days_in_month() as i32 - day;
                     let bucket = ordinal_from_end / 7 + 1;
                     bucket == nth_abs
                 }
@@ -141,7 +484,9 @@ impl HolidayRule {
                 offset,
                 years,
             } => {
-                if !years.contains(year) {
+                if !years.contains(year)
+                    || !(easter::FIRST_YEAR..=easter::LAST_YEAR).contains(&year)
+                {
                     return false;
                 }
                 let easter_doy = if western {
@@ -152,6 +497,15 @@ impl HolidayRule {
                 let target = easter_doy + offset;
                 date.day_of_year() as i32 == target
             }
+            HolidayRule::WeekdayOnOrAfter { .. }
+            | HolidayRule::WeekdayOnOrBefore { .. }
+            | HolidayRule::WeekdayAfter { .. } => {
+                // An offset can push the holiday into the neighbouring year.
+                (year - 1..=year + 1).any(|y| self.actual_date(y) == Some(date))
+            }
+            HolidayRule::Relative { .. } => false,
+            HolidayRule::Dates(table) => table.contains(date),
+            HolidayRule::Custom(rule) => rule.is_holiday(date),
             HolidayRule::OneOff {
                 year: y,
                 month,
@@ -159,4 +513,341 @@ impl HolidayRule {
             } => year == y && date.month() == month && date.day() == day,
         }
     }
+
+    /// Date on which this rule's holiday falls in `year`, before any weekend
+    /// adjustment, or `None` if the rule is inactive that year. Date-table
+    /// and custom rules with several holidays a year report the first.
+    pub fn actual_date(&self, year: i32) -> Option<Date> {
+        let ymd = |m, d| NaiveDate::from_ymd_opt(year, m, d).map(Date);
+        match *self {
+            HolidayRule::Fixed { month, day, years }
+            | HolidayRule::WeekendAdjustedFixed { month, day, years } => {
+                years.contains(year).then(|| ymd(month, day)).flatten()
+            }
+            HolidayRule::NthWeekday {
+                month,
+                weekday,
+                nth,
+                years,
+            } => years
+                .contains(year)
+                .then(|| Date::nth_weekday_of_month(year, month, weekday, nth))
+                .flatten(),
+            HolidayRule::EasterOffset {
+                western,
+                offset,
+                years,
+            } => {
+                if !years.contains(year)
+                    || !(easter::FIRST_YEAR..=easter::LAST_YEAR).contains(&year)
+                {
+                    return None;
+                }
+                let easter_doy = if western {
+                    western_easter_monday(year)
+                } else {
+                    orthodox_easter_monday(year)
+                } as i32;
+                Some(Date::ymd(year, 1, 1).add_days(easter_doy - 1 + offset))
+            }
+            HolidayRule::WeekdayOnOrAfter {
+                month,
+                day,
+                weekday,
+                offset,
+                years,
+            } => {
+                let anchor = years.contains(year).then(|| ymd(month, day)).flatten()?;
+                Some(weekday_on_or_after(anchor, weekday).add_days(offset))
+            }
+            HolidayRule::WeekdayOnOrBefore {
+                month,
+                day,
+                weekday,
+                offset,
+                years,
+            } => {
+                let anchor = years.contains(year).then(|| ymd(month, day)).flatten()?;
+                Some(weekday_on_or_before(anchor, weekday).add_days(offset))
+            }
+            HolidayRule::WeekdayAfter {
+                month,
+                day,
+                weekday,
+                offset,
+                years,
+            } => {
+                let anchor = years.contains(year).then(|| ymd(month, day)).flatten()?;
+                Some(weekday_on_or_after(anchor.add_days(1), weekday).add_days(offset))
+            }
+            HolidayRule::Relative { .. } => None,
+            HolidayRule::Dates(table) => table.in_year(year).next(),
+            HolidayRule::Custom(rule) => rule.holidays_in_year(year).first().copied(),
+            HolidayRule::OneOff {
+                year: y,
+                month,
+                day,
+            } => (year == y).then(|| ymd(month, day)).flatten(),
+        }
+    }
+
+    /// Date on which this rule's holiday is observed in `year`: the actual
+    /// date, moved off the weekend for weekend-adjusted rules unless that
+    /// would leave its month (the dates [`HolidayRule::matches`] accepts).
+    pub fn observed_date(&self, year: i32) -> Option<Date> {
+        let actual = self.actual_date(year)?;
+        let moved = match (self, actual.weekday()) {
+            (HolidayRule::WeekendAdjustedFixed { .. }, Weekday::Sat) => actual.add_days(-1),
+            (HolidayRule::WeekendAdjustedFixed { .. }, Weekday::Sun) => actual.add_days(1),
+            _ => actual,
+        };
+        Some(if moved.month() == actual.month() {
+            moved
+        } else {
+            actual
+        })
+    }
+}
+
+/// First `weekday` on or after `date`.
+fn weekday_on_or_after(date: Date, weekday: Weekday) -> Date {
+    let ahead =
+        weekday.num_days_from_monday() as i32 - date.weekday().num_days_from_monday() as i32;
+    date.add_days(ahead.rem_euclid(7))
+}
+
+/// Last `weekday` on or before `date`.
+fn weekday_on_or_before(date: Date, weekday: Weekday) -> Date {
+    let behind =
+        date.weekday().num_days_from_monday() as i32 - weekday.num_days_from_monday() as i32;
+    date.add_days(-behind.rem_euclid(7))
+}
+
+/// What a [`NamedRule`] does to the dates it matches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RuleEffect {
+    /// The dates are holidays.
+    Holiday,
+    /// The dates are business days, even on the weekend or a holiday (e.g.
+    /// make-up working days in China and Russia).
+    WorkingDay,
+}
+
+/// A holiday rule together with the name and category of the holiday it
+/// defines.
+///
+/// Several rules may share a name when one holiday needs more than one rule
+/// (e.g. a bank holiday moved in some years).
+///
+/// `effective_from` / `effective_to` restrict the rule to observed dates in
+/// that (inclusive) window, for changes that take effect mid-year, and
+/// `cycle` to every Nth year; the rule's own year range still applies.
+/// Rules built with [`NamedRule::working_day`] open dates instead of
+/// closing them. `announced` records when the rule became known (e.g. the
+/// announcement of a special closure), for [`DerivedCalendar::as_of`]
+/// views; rules without it are taken to have always been known.
+///
+/// [`DerivedCalendar::as_of`]: crate::DerivedCalendar::as_of
+#[derive(Debug, Clone, Copy)]
+pub struct NamedRule {
+    pub name: &'static str,
+    pub category: HolidayCategory,
+    pub rule: HolidayRule,
+    pub effective_from: Option<Date>,
+    pub effective_to: Option<Date>,
+    pub cycle: Option<YearCycle>,
+    pub effect: RuleEffect,
+    pub announced: Option<Date>,
+}
+
+impl NamedRule {
+    /// A [`HolidayCategory::Statutory`] rule; see [`NamedRule::with_category`].
+    #[inline]
+    pub const fn new(name: &'static str, rule: HolidayRule) -> Self {
+        Self {
+            name,
+            category: HolidayCategory::Statutory,
+            rule,
+            effective_from: None,
+            effective_to: None,
+            cycle: None,
+            effect: RuleEffect::Holiday,
+            announced: None,
+        }
+    }
+
+    /// A rule making the dates it matches business days, taking precedence
+    /// over the weekend and over holiday rules.
+    #[inline]
+    pub const fn working_day(name: &'static str, rule: HolidayRule) -> Self {
+        let mut rule = Self::new(name, rule);
+        rule.effect = RuleEffect::WorkingDay;
+        rule
+    }
+
+    /// This rule renamed to `name`.
+    #[inline]
+    pub const fn named(mut self, name: &'static str) -> Self {
+        self.name = name;
+        self
+    }
+
+    /// This rule with its effect set to `effect`.
+    #[inline]
+    pub const fn with_effect(mut self, effect: RuleEffect) -> Self {
+        self.effect = effect;
+        self
+    }
+
+    /// This rule restricted to `years`; see [`HolidayRule::with_years`].
+    #[inline]
+    pub const fn with_years(mut self, years: YearRange) -> Self {
+        self.rule = self.rule.with_years(years);
+        self
+    }
+
+    /// This rule restricted to `year` onwards.
+    #[inline]
+    pub const fn since(self, year: i32) -> Self {
+        self.with_years(YearRange::from(year))
+    }
+
+    /// This rule restricted to years up to and including `year`.
+    #[inline]
+    pub const fn until(self, year: i32) -> Self {
+        self.with_years(YearRange::until(year))
+    }
+
+    /// This rule restricted to `start..=end`.
+    #[inline]
+    pub const fn between(self, start: i32, end: i32) -> Self {
+        self.with_years(YearRange::between(start, end))
+    }
+
+    /// This fixed-date rule, observed on the nearest weekday when it falls on
+    /// a weekend (see [`HolidayRule::WeekendAdjustedFixed`]).
+    ///
+    /// # Panics
+    ///
+    /// If the rule is not a [`HolidayRule::Fixed`] rule.
+    #[inline]
+    pub const fn observed(mut self) -> Self {
+        self.rule = match self.rule {
+            HolidayRule::Fixed { month, day, years } => {
+                HolidayRule::WeekendAdjustedFixed { month, day, years }
+            }
+            _ => panic!("only fixed-date rules can be weekend-observed"),
+        };
+        self
+    }
+
+    /// This weekday rule, shifted by `days` days (e.g. Midsummer Eve, the day
+    /// before the first Saturday after 19 June).
+    ///
+    /// # Panics
+    ///
+    /// If the rule is not a [`HolidayRule::WeekdayOnOrAfter`],
+    /// [`HolidayRule::WeekdayOnOrBefore`] or [`HolidayRule::WeekdayAfter`]
+    /// rule.
+    #[inline]
+    pub const fn shifted(mut self, days: i32) -> Self {
+        match &mut self.rule {
+            HolidayRule::WeekdayOnOrAfter { offset, .. }
+            | HolidayRule::WeekdayOnOrBefore { offset, .. }
+            | HolidayRule::WeekdayAfter { offset, .. } => *offset += days,
+            _ => panic!("only weekday rules can be shifted"),
+        }
+        self
+    }
+
+    /// This rule with its category set to `category`.
+    #[inline]
+    pub const fn with_category(mut self, category: HolidayCategory) -> Self {
+        self.category = category;
+        self
+    }
+
+    /// This rule, effective only for holidays observed on or after `date`.
+    #[inline]
+    pub const fn effective_from(mut self, date: Date) -> Self {
+        self.effective_from = Some(date);
+        self
+    }
+
+    /// This rule, effective only for holidays observed on or before `date`.
+    #[inline]
+    pub const fn effective_to(mut self, date: Date) -> Self {
+        self.effective_to = Some(date);
+        self
+    }
+
+    /// This rule, announced on `date`.
+    #[inline]
+    pub const fn announced_on(mut self, date: Date) -> Self {
+        self.announced = Some(date);
+        self
+    }
+
+    /// This rule, active only in years of `cycle`.
+    #[inline]
+    pub const fn every(mut self, cycle: YearCycle) -> Self {
+        self.cycle = Some(cycle);
+        self
+    }
+
+    /// Returns `true` if `year` belongs to this rule's year cycle (always
+    /// `true` without one).
+    #[inline]
+    pub fn is_cycle_year(&self, year: i32) -> bool {
+        self.cycle.is_none_or(|cycle| cycle.contains(year))
+    }
+
+    /// Returns `true` if this rule had been announced by `date` (always `true`
+    /// without an announcement date).
+    #[inline]
+    pub fn is_known_at(&self, date: Date) -> bool {
+        self.announced.is_none_or(|announced| announced <= date)
+    }
+
+    /// Returns `true` if `date` lies within this rule's effective window.
+    #[inline]
+    pub fn is_effective(&self, date: Date) -> bool {
+        self.effective_from.is_none_or(|from| date >= from)
+            && self.effective_to.is_none_or(|to| date <= to)
+    }
+
+    /// Returns `true` if this rule marks the given `date` as a holiday (or,
+    /// for [`RuleEffect::WorkingDay`] rules, as a working day).
+    #[inline]
+    pub fn is_holiday(&self, date: Date, year: i32) -> bool {
+        self.is_cycle_year(year) && self.rule.matches(date, year) && self.is_effective(date)
+    }
+
+    /// This rule's holiday in `year`, or `None` if the rule is inactive that year.
+    pub fn occurrence(&self, year: i32) -> Option<Occurrence> {
+        if !self.is_cycle_year(year) {
+            return None;
+        }
+        let observed = self.rule.observed_date(year)?;
+        if !self.is_effective(observed) {
+            return None;
+        }
+        Some(Occurrence {
+            name: self.name,
+            category: self.category,
+            actual: self.rule.actual_date(year)?,
+            observed,
+        })
+    }
+}
+
+/// A named holiday on a specific date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Occurrence {
+    pub name: &'static str,
+    pub category: HolidayCategory,
+    /// Date the holiday falls on.
+    pub actual: Date,
+    /// Date the holiday is observed (differs from `actual` when moved off a weekend).
+    pub observed: Date,
 }
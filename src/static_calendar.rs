@@ -1,35 +1,116 @@
+//! Rule-table calendars.
+
 use chrono::Weekday;
 
-use crate::calendar::Calendar;
+use crate::calendar::{Calendar, HOLIDAY_SEARCH_YEARS};
 use crate::date::Date;
-use crate::rules::HolidayRule;
+use crate::easter;
+use crate::explain::{Explanation, Override};
+use crate::rules::{
+    HolidayRule, HolidayRuleLike, NamedRule, Occurrence, RelativeCondition, RuleEffect, RuleRef,
+    YearRange,
+};
+
+/// [`HolidayRule::Relative`] occurrences resolved during one query, keyed by
+/// rule and year, so that each is resolved at most once however many
+/// `Sandwiched` neighbour checks ask for it. A rule still being resolved
+/// reads as not matching, which also cuts reference cycles.
+#[derive(Default)]
+struct Resolved {
+    entries: Vec<ResolvedEntry>,
+}
+
+struct ResolvedEntry {
+    rule: *const NamedRule,
+    year: i32,
+    /// `None` while the rule is still being resolved.
+    occurrence: Option<Option<Occurrence>>,
+}
+
+impl Resolved {
+    fn entry(&mut self, rule: &NamedRule, year: i32) -> Option<&mut ResolvedEntry> {
+        self.entries
+            .iter_mut()
+            .find(|e| std::ptr::eq(e.rule, rule) && e.year == year)
+    }
+}
 
 /// Simple static calendar over a set of `HolidayRule`s.
+///
+/// The rule table is usually `'static`; shorter-lived tables are used by
+/// [`DerivedCalendar`](crate::DerivedCalendar).
 #[derive(Debug)]
-pub struct StaticCalendar {
-    pub name: &'static str,
+pub struct StaticCalendar<'a> {
+    pub name: &'a str,
     pub weekend: [Weekday; 2],
-    pub rules: &'static [HolidayRule],
+    pub rules: &'a [NamedRule],
+    /// Years over which the rules are believed complete; see
+    /// [`Calendar::supported_years`].
+    pub horizon: YearRange,
 }
 
-impl StaticCalendar {
-    /// Returns `true` if the given date is considered a weekend in this calendar.
+impl<'a> StaticCalendar<'a> {
+    #[inline]
+    pub const fn new(name: &'a str, weekend: [Weekday; 2], rules: &'a [NamedRule]) -> Self {
+        Self {
+            name,
+            weekend,
+            rules,
+            horizon: YearRange::always(),
+        }
+    }
+
+    /// This calendar, with its rules believed complete over `horizon` only.
+    #[inline]
+    pub const fn supported_over(mut self, horizon: YearRange) -> Self {
+        self.horizon = horizon;
+        self
+    }
+
+    /// Returns `true` if the given date is considered a weekend in this
+    /// calendar: a weekend weekday not made a working day by a
+    /// [`RuleEffect::WorkingDay`] rule.
     #[inline]
     pub fn is_weekend(&self, date: Date) -> bool {
-        let wd = date.weekday();
-        wd == self.weekend[0] || wd == self.weekend[1]
+        self.is_weekend_weekday(date.weekday()) && !self.is_working_day(date)
     }
 
-    /// Returns `true` if any of this calendar's rules marks `date` as a holiday.
+    /// Returns `true` if `date` is a holiday: a non-weekend day closed by one
+    /// of this calendar's rules and not reopened by a working-day rule.
     #[inline]
     pub fn is_holiday(&self, date: Date) -> bool {
-        let y = date.year();
-        for &rule in self.rules {
-            if rule.is_holiday(date, y) {
-                return true;
-            }
-        }
-        false
+        !self.is_weekend_weekday(date.weekday())
+            && self.has_holiday_rule_at(date, &mut Resolved::default())
+            && !self.is_working_day(date)
+    }
+
+    /// Returns `true` if a [`RuleEffect::WorkingDay`] rule makes `date` a
+    /// business day, overriding the weekend and holiday rules.
+    #[inline]
+    pub fn is_working_day(&self, date: Date) -> bool {
+        self.matching_working_day_rules(date).next().is_some()
+    }
+
+    /// Holiday rules of this calendar that match `date` (whether or not
+    /// `date` ends up closed).
+    #[inline]
+    pub fn matching_rules(&self, date: Date) -> impl Iterator<Item = &'a NamedRule> + '_ {
+        let mut resolved = Resolved::default();
+        self.rules
+            .iter()
+            .filter(move |rule| self.rule_matches(rule, RuleEffect::Holiday, date, &mut resolved))
+    }
+
+    /// Working-day rules of this calendar that match `date`.
+    #[inline]
+    pub fn matching_working_day_rules(
+        &self,
+        date: Date,
+    ) -> impl Iterator<Item = &'a NamedRule> + '_ {
+        let mut resolved = Resolved::default();
+        self.rules.iter().filter(move |rule| {
+            self.rule_matches(rule, RuleEffect::WorkingDay, date, &mut resolved)
+        })
     }
 
     /// Returns `true` if `date` is a business day (non-weekend, non-holiday).
@@ -37,11 +118,239 @@ impl StaticCalendar {
     pub fn is_business_day(&self, date: Date) -> bool {
         !self.is_weekend(date) && !self.is_holiday(date)
     }
+
+    #[inline]
+    fn is_weekend_weekday(&self, weekday: Weekday) -> bool {
+        weekday == self.weekend[0] || weekday == self.weekend[1]
+    }
+
+    /// `rule`'s holiday in `year`, resolving [`HolidayRule::Relative`] rules
+    /// against the other rules of this calendar.
+    pub fn occurrence(&self, rule: &NamedRule, year: i32) -> Option<Occurrence> {
+        self.resolve(rule, year, &mut Resolved::default())
+    }
+
+    /// First holiday observed strictly after `date`, found by evaluating each
+    /// rule once per year rather than scanning day by day.
+    pub fn next_holiday(&self, date: Date) -> Option<Occurrence> {
+        (date.year()..=date.year() + HOLIDAY_SEARCH_YEARS).find_map(|year| {
+            self.observed_holidays(year)
+                .filter(|occ| occ.observed > date)
+                .min_by_key(|occ| occ.observed)
+        })
+    }
+
+    /// Last holiday observed strictly before `date`.
+    pub fn previous_holiday(&self, date: Date) -> Option<Occurrence> {
+        (date.year() - HOLIDAY_SEARCH_YEARS..=date.year())
+            .rev()
+            .find_map(|year| {
+                self.observed_holidays(year)
+                    .filter(|occ| occ.observed < date)
+                    .rev()
+                    .max_by_key(|occ| occ.observed)
+            })
+    }
+
+    /// Occurrences in `year` whose observed date is a holiday (rules whose
+    /// date falls on a weekend without being moved do not count).
+    fn observed_holidays(&self, year: i32) -> impl DoubleEndedIterator<Item = Occurrence> + '_ {
+        self.rules
+            .iter()
+            .filter(|rule| rule.effect == RuleEffect::Holiday)
+            .flat_map(move |rule| self.occurrences_in(rule, year))
+            .filter(|occ| self.is_holiday(occ.observed))
+    }
+
+    /// Every holiday of `rule` in `year`: date-table and custom rules may
+    /// have several.
+    pub(crate) fn occurrences_in(&self, rule: &NamedRule, year: i32) -> Vec<Occurrence> {
+        match rule.rule {
+            HolidayRule::Dates(_) | HolidayRule::Custom(_) if rule.is_cycle_year(year) => rule
+                .rule
+                .holidays_in_year(year)
+                .into_iter()
+                .filter(|&d| rule.is_effective(d))
+                .map(|d| Occurrence {
+                    name: rule.name,
+                    category: rule.category,
+                    actual: d,
+                    observed: d,
+                })
+                .collect(),
+            _ => self.occurrence(rule, year).into_iter().collect(),
+        }
+    }
+
+    fn has_holiday_rule_at(&self, date: Date, resolved: &mut Resolved) -> bool {
+        self.rules
+            .iter()
+            .any(|rule| self.rule_matches(rule, RuleEffect::Holiday, date, resolved))
+    }
+
+    fn rule_matches(
+        &self,
+        rule: &NamedRule,
+        effect: RuleEffect,
+        date: Date,
+        resolved: &mut Resolved,
+    ) -> bool {
+        if rule.effect != effect {
+            return false;
+        }
+        match rule.rule {
+            HolidayRule::Relative { .. } => self
+                .resolve(rule, date.year(), resolved)
+                .is_some_and(|occ| occ.observed == date),
+            _ => rule.is_holiday(date, date.year()),
+        }
+    }
+
+    fn resolve(&self, rule: &NamedRule, year: i32, resolved: &mut Resolved) -> Option<Occurrence> {
+        if !matches!(rule.rule, HolidayRule::Relative { .. }) {
+            return rule.occurrence(year);
+        }
+        if let Some(entry) = resolved.entry(rule, year) {
+            return entry.occurrence.flatten();
+        }
+        resolved.entries.push(ResolvedEntry {
+            rule,
+            year,
+            occurrence: None,
+        });
+        let occurrence = self.resolve_relative(rule, year, resolved);
+        if let Some(entry) = resolved.entry(rule, year) {
+            entry.occurrence = Some(occurrence);
+        }
+        occurrence
+    }
+
+    fn resolve_relative(
+        &self,
+        rule: &NamedRule,
+        year: i32,
+        resolved: &mut Resolved,
+    ) -> Option<Occurrence> {
+        let HolidayRule::Relative {
+            base,
+            offset,
+            condition,
+            years,
+        } = rule.rule
+        else {
+            return rule.occurrence(year);
+        };
+        if !years.contains(year) || !rule.is_cycle_year(year) {
+            return None;
+        }
+
+        let base = match base {
+            RuleRef::Index(i) => self.resolve(self.rules.get(i)?, year, resolved)?,
+            RuleRef::Name(name) => self
+                .rules
+                .iter()
+                .filter(|r| r.name == name)
+                .find_map(|r| self.resolve(r, year, resolved))?,
+        };
+        let date = base.observed.add_days(offset);
+        let applies = match condition {
+            RelativeCondition::Always => true,
+            RelativeCondition::Weekday => !self.is_weekend_weekday(date.weekday()),
+            RelativeCondition::BaseOn(weekday) => base.observed.weekday() == weekday,
+            RelativeCondition::Moved => base.observed != base.actual,
+            RelativeCondition::Sandwiched => {
+                self.has_holiday_rule_at(date.add_days(-1), resolved)
+                    && self.has_holiday_rule_at(date.add_days(1), resolved)
+            }
+        };
+        (applies && rule.is_effective(date)).then_some(Occurrence {
+            name: rule.name,
+            category: rule.category,
+            actual: date,
+            observed: date,
+        })
+    }
 }
 
-impl Calendar for StaticCalendar {
+impl Calendar for StaticCalendar<'_> {
+    #[inline]
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    /// The calendar's horizon, narrowed to the Easter table's years if a rule
+    /// depends on Easter.
+    fn supported_years(&self) -> YearRange {
+        let uses_easter = self
+            .rules
+            .iter()
+            .any(|rule| matches!(rule.rule, HolidayRule::EasterOffset { .. }));
+        if uses_easter {
+            self.horizon
+                .intersection(YearRange::between(easter::FIRST_YEAR, easter::LAST_YEAR))
+        } else {
+            self.horizon
+        }
+    }
+
     #[inline]
     fn is_business_day(&self, date: Date) -> bool {
         self.is_business_day(date)
     }
+
+    #[inline]
+    fn is_weekend(&self, date: Date) -> bool {
+        self.is_weekend(date)
+    }
+
+    #[inline]
+    fn is_always_closed_on(&self, weekday: Weekday) -> bool {
+        self.is_weekend_weekday(weekday)
+            && !self
+                .rules
+                .iter()
+                .any(|rule| rule.effect == RuleEffect::WorkingDay)
+    }
+
+    fn matching_rules(&self, date: Date) -> Vec<NamedRule> {
+        self.matching_rules(date).copied().collect()
+    }
+
+    fn explain(&self, date: Date) -> Explanation {
+        Explanation {
+            date,
+            business_day: self.is_business_day(date),
+            weekend: self.is_weekend(date),
+            holidays: Calendar::matching_occurrences(self, date),
+            overrides: self
+                .matching_working_day_rules(date)
+                .map(|rule| Override {
+                    description: rule.name,
+                    business_day: true,
+                })
+                .collect(),
+        }
+    }
+
+    fn matching_occurrences(&self, date: Date) -> Vec<Occurrence> {
+        self.matching_rules(date)
+            .filter_map(|rule| match rule.rule {
+                HolidayRule::Dates(_) | HolidayRule::Custom(_) => Some(Occurrence {
+                    name: rule.name,
+                    category: rule.category,
+                    actual: date,
+                    observed: date,
+                }),
+                _ => self.occurrence(rule, date.year()),
+            })
+            .collect()
+    }
+
+    fn next_holiday(&self, date: Date) -> Option<Occurrence> {
+        self.next_holiday(date)
+    }
+
+    fn previous_holiday(&self, date: Date) -> Option<Occurrence> {
+        self.previous_holiday(date)
+    }
 }
@@ -0,0 +1,302 @@
+//! Lint pass over the rule table of a rule-based calendar.
+
+use std::fmt;
+
+use crate::date::{is_valid_date, Date};
+use crate::derived_calendar::DerivedCalendar;
+use crate::easter;
+use crate::rules::{HolidayRule, RuleRef, YearCycle, YearRange};
+use crate::static_calendar::StaticCalendar;
+
+/// A likely mistake in a calendar's rule table, found by
+/// [`StaticCalendar::validate`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// Position of the offending rule in the calendar's rule table.
+    pub index: usize,
+    /// Name of the offending rule.
+    pub rule: &'static str,
+    pub problem: Problem,
+}
+
+/// What is wrong with a rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Problem {
+    /// The rule's date exists in no year (e.g. 30 February), or, for a
+    /// one-off (`year` is `Some`), not in its year.
+    ImpossibleDate {
+        year: Option<i32>,
+        month: u32,
+        day: u32,
+    },
+    /// An nth-weekday rule whose month is not 1 to 12.
+    ImpossibleMonth(u32),
+    /// An nth-weekday rule whose `nth` is 0 or beyond ±5, so it never matches.
+    NthOutOfRange(i8),
+    /// A year range whose start is after its end, so the rule never applies.
+    InvertedYearRange(YearRange),
+    /// The rule matches exactly the same days as an earlier rule in every
+    /// year where both apply.
+    Duplicate { index: usize, rule: &'static str },
+    /// A one-off holiday on a weekend, which closes nothing.
+    OneOffOnWeekend(Date),
+    /// An Easter rule applying in years the Easter tables do not cover.
+    EasterOutOfRange(YearRange),
+    /// A year cycle whose modulus is not positive, so the rule never applies.
+    InvalidYearCycle(YearCycle),
+    /// A relative rule whose chain of bases leads back to itself, so it
+    /// never matches.
+    RelativeCycle,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "rule #{} {:?}: {}", self.index, self.rule, self.problem)
+    }
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Problem::ImpossibleDate {
+                year: Some(year),
+                month,
+                day,
+            } => write!(f, "{year}-{month:02}-{day:02} does not exist"),
+            Problem::ImpossibleDate {
+                year: None,
+                month,
+                day,
+            } => write!(f, "--{month:02}-{day:02} does not exist"),
+            Problem::ImpossibleMonth(month) => write!(f, "month {month} does not exist"),
+            Problem::NthOutOfRange(nth) => write!(f, "nth weekday {nth} never matches"),
+            Problem::InvertedYearRange(years) => {
+                write!(f, "year range {}..={} is empty", years.start, years.end)
+            }
+            Problem::Duplicate { index, rule } => {
+                write!(f, "matches the same days as rule #{index} {rule:?}")
+            }
+            Problem::OneOffOnWeekend(date) => write!(f, "{} is a weekend", date.0),
+            Problem::EasterOutOfRange(years) => write!(
+                f,
+                "applies in {}..={}, beyond the Easter tables ({}..={})",
+                years.start,
+                years.end,
+                easter::FIRST_YEAR,
+                easter::LAST_YEAR
+            ),
+            Problem::InvalidYearCycle(cycle) => {
+                write!(f, "year cycle modulus {} is not positive", cycle.modulus)
+            }
+            Problem::RelativeCycle => write!(f, "its base refers back to it"),
+        }
+    }
+}
+
+impl StaticCalendar<'_> {
+    /// Checks this calendar's rules for likely mistakes: impossible dates,
+    /// nth-weekday rules that never match, empty year ranges and year
+    /// cycles, duplicated rules, one-offs on weekends, Easter rules beyond
+    /// the Easter tables and relative rules based on themselves.
+    ///
+    /// Duplicates are looked for over the years of the Easter tables.
+    ///
+    /// ```rust
+    /// use business_calendar::markets::us::nyse::US_NYSE;
+    ///
+    /// assert!(US_NYSE.validate().is_empty());
+    /// ```
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+        for (index, rule) in self.rules.iter().enumerate() {
+            let mut report = |problem| {
+                diagnostics.push(Diagnostic {
+                    index,
+                    rule: rule.name,
+                    problem,
+                })
+            };
+            if let Some(years) = rule.rule.years() {
+                if years.start > years.end {
+                    report(Problem::InvertedYearRange(years));
+                }
+            }
+            if let Some(cycle) = rule.cycle {
+                if cycle.modulus <= 0 {
+                    report(Problem::InvalidYearCycle(cycle));
+                }
+            }
+            match rule.rule {
+                HolidayRule::Fixed { month, day, .. }
+                | HolidayRule::WeekendAdjustedFixed { month, day, .. }
+                | HolidayRule::WeekdayOnOrAfter { month, day, .. }
+                | HolidayRule::WeekdayOnOrBefore { month, day, .. }
+                | HolidayRule::WeekdayAfter { month, day, .. }
+                    // 2000 is a leap year, so 29 February is allowed.
+                    if !is_valid_date(2000, month, day) =>
+                {
+                    report(Problem::ImpossibleDate {
+                        year: None,
+                        month,
+                        day,
+                    });
+                }
+                HolidayRule::NthWeekday { month, nth, .. } => {
+                    if !(1..=12).contains(&month) {
+                        report(Problem::ImpossibleMonth(month));
+                    }
+                    if nth == 0 || !(-5..=5).contains(&nth) {
+                        report(Problem::NthOutOfRange(nth));
+                    }
+                }
+                HolidayRule::EasterOffset { years, .. }
+                    if years.start <= years.end
+                        && (years.start < easter::FIRST_YEAR || years.end > easter::LAST_YEAR) =>
+                {
+                    report(Problem::EasterOutOfRange(years));
+                }
+                HolidayRule::OneOff { year, month, day } => {
+                    if !is_valid_date(year, month, day) {
+                        report(Problem::ImpossibleDate {
+                            year: Some(year),
+                            month,
+                            day,
+                        });
+                    } else if rule.effect == crate::RuleEffect::Holiday
+                        && self.weekend.contains(&Date::ymd(year, month, day).weekday())
+                    {
+                        report(Problem::OneOffOnWeekend(Date::ymd(year, month, day)));
+                    }
+                }
+                _ => {}
+            }
+        }
+        diagnostics.extend(self.duplicates());
+        diagnostics.extend(self.relative_cycles());
+        diagnostics.sort_by_key(|d| d.index);
+        diagnostics
+    }
+
+    /// Rules matching the same days as an earlier rule in every year both
+    /// apply (and at least one).
+    fn duplicates(&self) -> Vec<Diagnostic> {
+        let years = easter::FIRST_YEAR..=easter::LAST_YEAR;
+        let dates: Vec<Vec<Vec<Date>>> = self
+            .rules
+            .iter()
+            .map(|rule| {
+                if !self.is_checkable(rule) {
+                    return Vec::new();
+                }
+                years
+                    .clone()
+                    .map(|year| {
+                        let mut dates: Vec<Date> = self
+                            .occurrences_in(rule, year)
+                            .iter()
+                            .map(|occ| occ.observed)
+                            .collect();
+                        dates.sort();
+                        dates
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let mut diagnostics = Vec::new();
+        for (j, later) in self.rules.iter().enumerate() {
+            let earlier = (0..j).find(|&i| {
+                let (a, b) = (&dates[i], &dates[j]);
+                if a.is_empty() || b.is_empty() || self.rules[i].effect != later.effect {
+                    return false;
+                }
+                let mut common = a
+                    .iter()
+                    .zip(b)
+                    .filter(|(a, b)| !a.is_empty() && !b.is_empty());
+                let mut any = false;
+                let all_equal = common.all(|(a, b)| {
+                    any = true;
+                    a == b
+                });
+                any && all_equal
+            });
+            if let Some(i) = earlier {
+                diagnostics.push(Diagnostic {
+                    index: j,
+                    rule: later.name,
+                    problem: Problem::Duplicate {
+                        index: i,
+                        rule: self.rules[i].name,
+                    },
+                });
+            }
+        }
+        diagnostics
+    }
+
+    /// Relative rules that reach themselves by following their bases. A
+    /// base referenced by name may be any rule of that name.
+    fn relative_cycles(&self) -> Vec<Diagnostic> {
+        let bases = |i: usize| -> Vec<usize> {
+            match self.rules[i].rule {
+                HolidayRule::Relative {
+                    base: RuleRef::Index(j),
+                    ..
+                } if j < self.rules.len() => vec![j],
+                HolidayRule::Relative {
+                    base: RuleRef::Name(name),
+                    ..
+                } => (0..self.rules.len())
+                    .filter(|&j| self.rules[j].name == name)
+                    .collect(),
+                _ => Vec::new(),
+            }
+        };
+        (0..self.rules.len())
+            .filter(|&i| {
+                let mut seen = vec![false; self.rules.len()];
+                let mut stack = bases(i);
+                while let Some(j) = stack.pop() {
+                    if j == i {
+                        return true;
+                    }
+                    if !std::mem::replace(&mut seen[j], true) {
+                        stack.extend(bases(j));
+                    }
+                }
+                false
+            })
+            .map(|i| Diagnostic {
+                index: i,
+                rule: self.rules[i].name,
+                problem: Problem::RelativeCycle,
+            })
+            .collect()
+    }
+
+    /// Returns `true` if `rule` can be evaluated over the Easter-table years
+    /// without tripping over the problems reported separately.
+    fn is_checkable(&self, rule: &crate::NamedRule) -> bool {
+        let valid_years = rule.rule.years().is_none_or(|y| y.start <= y.end);
+        let valid_cycle = rule.cycle.is_none_or(|c| c.modulus > 0);
+        let valid_dates = match rule.rule {
+            HolidayRule::Fixed { month, day, .. }
+            | HolidayRule::WeekendAdjustedFixed { month, day, .. }
+            | HolidayRule::WeekdayOnOrAfter { month, day, .. }
+            | HolidayRule::WeekdayOnOrBefore { month, day, .. }
+            | HolidayRule::WeekdayAfter { month, day, .. } => is_valid_date(2000, month, day),
+            HolidayRule::NthWeekday { month, .. } => (1..=12).contains(&month),
+            HolidayRule::OneOff { year, month, day } => is_valid_date(year, month, day),
+            _ => true,
+        };
+        valid_years && valid_cycle && valid_dates
+    }
+}
+
+impl DerivedCalendar {
+    /// Checks the effective rule table; see [`StaticCalendar::validate`].
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        self.as_static().validate()
+    }
+}
@@ -0,0 +1,71 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::thread;
+
+use business_calendar::fx::Currency;
+use business_calendar::markets::eu::{EUMarket, EU_TARGET};
+use business_calendar::markets::uk::UKMarket;
+use business_calendar::markets::us::nyse::US_NYSE;
+use business_calendar::markets::us::{USMarket, UnitedStates};
+use business_calendar::{AnyCalendar, Calendar, Date, DateRange};
+
+fn count_business_days(cal: impl Calendar, range: DateRange) -> usize {
+    cal.business_days(range).count()
+}
+
+#[test]
+fn pointer_types_are_calendars() {
+    let july = DateRange::inclusive(Date::ymd(2025, 7, 1), Date::ymd(2025, 7, 31));
+    let nyse = UnitedStates::new(USMarket::NYSE);
+    let boxed: Box<dyn Calendar> = Box::new(nyse);
+    let shared: Arc<dyn Calendar> = Arc::new(nyse);
+
+    assert_eq!(count_business_days(nyse, july), 22);
+    assert_eq!(count_business_days(&US_NYSE, july), 22);
+    assert_eq!(count_business_days(&boxed, july), 22);
+    assert_eq!(count_business_days(boxed, july), 22);
+    assert_eq!(count_business_days(shared.clone(), july), 22);
+    assert_eq!(shared.code(), "US-NYSE");
+}
+
+#[test]
+fn calendars_can_be_shared_across_threads() {
+    let shared: Arc<dyn Calendar> = Arc::new(AnyCalendar::from(UKMarket::Exchange));
+    let handles: Vec<_> = (2020..2024)
+        .map(|year| {
+            let cal = Arc::clone(&shared);
+            thread::spawn(move || cal.is_business_day(Date::ymd(year, 12, 25)))
+        })
+        .collect();
+    assert!(handles.into_iter().all(|h| !h.join().unwrap()));
+}
+
+#[test]
+fn any_calendar_matches_the_wrapped_market() {
+    let mut codes = HashSet::new();
+    for cal in AnyCalendar::ALL {
+        assert!(codes.insert(cal.code().to_owned()));
+    }
+    let set: HashSet<AnyCalendar> = AnyCalendar::ALL.into_iter().collect();
+    assert_eq!(set.len(), AnyCalendar::ALL.len());
+
+    let any = AnyCalendar::from(USMarket::FederalReserve);
+    let fed = UnitedStates::new(USMarket::FederalReserve);
+    assert_eq!(any, AnyCalendar::UnitedStates(fed));
+    let year = DateRange::inclusive(Date::ymd(2024, 1, 1), Date::ymd(2024, 12, 31));
+    assert!(any.business_days(year).eq(fed.business_days(year)));
+    assert_eq!(any.name(), fed.name());
+}
+
+#[test]
+fn any_calendar_covers_target() {
+    let target = AnyCalendar::from(EUMarket::Target);
+    assert!(AnyCalendar::ALL.contains(&target));
+    assert_eq!(target.code(), "EU-TARGET");
+    assert_eq!(target.name(), EU_TARGET.name);
+    assert_eq!(target.country_code(), None);
+    assert_eq!(target.settlement_currency(), Some(Currency::EUR));
+    // TARGET closes on Labour Day.
+    assert!(!target.is_business_day(Date::ymd(2025, 5, 1)));
+    assert!(target.is_business_day(Date::ymd(2025, 5, 2)));
+}
@@ -0,0 +1,166 @@
+use business_calendar::markets::uk::{UKMarket, UK_EXCHANGE};
+use business_calendar::markets::us::{fed::US_FED, nyse::US_NYSE, settlement::US_SETTLEMENT};
+use business_calendar::markets::us::{USMarket, UnitedStates};
+use business_calendar::{
+    AnyCalendar, Calendar, Date, HolidayCategory, HolidayRule, NamedRule, RuleEffect,
+    StaticCalendar, YearRange,
+};
+use chrono::Weekday;
+
+mod common;
+
+use common::{SHIPPED, SHIPPED_DERIVED};
+
+static RULES: [NamedRule; 2] = [
+    NamedRule::new(
+        "Christmas Day",
+        HolidayRule::Fixed {
+            month: 12,
+            day: 25,
+            years: YearRange::always(),
+        },
+    ),
+    NamedRule::new(
+        "Snow Day",
+        HolidayRule::OneOff {
+            year: 2024,
+            month: 1,
+            day: 16,
+        },
+    )
+    .announced_on(Date::ymd(2024, 1, 15)),
+];
+static SNOWY: StaticCalendar = StaticCalendar::new("Snowy", [Weekday::Sat, Weekday::Sun], &RULES);
+
+#[test]
+fn rules_are_hidden_until_announced() {
+    let snow_day = Date::ymd(2024, 1, 16);
+    assert!(!SNOWY.is_business_day(snow_day));
+
+    let before = SNOWY.as_of(Date::ymd(2024, 1, 14));
+    assert_eq!(before.knowledge_date(), Some(Date::ymd(2024, 1, 14)));
+    assert!(before.is_business_day(snow_day));
+    assert_eq!(before.rules().len(), 1);
+    // Rules without an announcement date are always known.
+    assert!(!before.is_business_day(Date::ymd(2024, 12, 25)));
+
+    let on_the_day = SNOWY.as_of(Date::ymd(2024, 1, 15));
+    assert!(!on_the_day.is_business_day(snow_day));
+}
+
+#[test]
+fn as_of_composes_with_other_derivations() {
+    let snow_day = Date::ymd(2024, 1, 16);
+    let cal = SNOWY
+        .without("Christmas Day")
+        .overlay(
+            NamedRule::new(
+                "Ice Day",
+                HolidayRule::OneOff {
+                    year: 2024,
+                    month: 1,
+                    day: 17,
+                },
+            )
+            .announced_on(Date::ymd(2024, 1, 16)),
+        )
+        .as_of(Date::ymd(2024, 1, 15));
+    assert!(!cal.is_business_day(snow_day));
+    assert!(cal.is_business_day(Date::ymd(2024, 1, 17)));
+    assert!(cal.is_business_day(Date::ymd(2024, 12, 25)));
+}
+
+#[test]
+fn shipped_closures_carry_announcement_dates() {
+    let sandy = [Date::ymd(2012, 10, 29), Date::ymd(2012, 10, 30)];
+    let friday = US_NYSE.as_of(Date::ymd(2012, 10, 26));
+    assert!(sandy.iter().all(|d| friday.is_business_day(*d)));
+
+    // The second day was only announced once the first was under way.
+    let sunday = UnitedStates::new(USMarket::NYSE).as_of(Date::ymd(2012, 10, 28));
+    assert!(!sunday.is_business_day(sandy[0]));
+    assert!(sunday.is_business_day(sandy[1]));
+
+    let any = AnyCalendar::from(USMarket::NYSE).as_of(Date::ymd(2012, 11, 1));
+    assert!(sandy.iter().all(|d| !any.is_business_day(*d)));
+}
+
+#[test]
+fn every_shipped_closure_carries_an_announcement_date() {
+    let statics = SHIPPED.map(|(path, _, cal)| (path, cal.rules));
+    let derived = SHIPPED_DERIVED.map(|(path, _, cal)| (path, cal.rules()));
+    for (path, rules) in statics.into_iter().chain(derived) {
+        for rule in rules {
+            if let HolidayRule::OneOff { year, month, day } = rule.rule {
+                if rule.category != HolidayCategory::Statutory
+                    || rule.effect == RuleEffect::WorkingDay
+                {
+                    let announced = rule.announced.unwrap_or_else(|| {
+                        panic!("{path}: {} has no announcement date", rule.name)
+                    });
+                    assert!(announced <= Date::ymd(year, month, day), "{}", rule.name);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn september_11_was_not_known_the_day_before() {
+    let eve = US_NYSE.as_of(Date::ymd(2001, 9, 10));
+    assert!(eve.is_business_day(Date::ymd(2001, 9, 11)));
+    let day_of = US_NYSE.as_of(Date::ymd(2001, 9, 11));
+    assert!(!day_of.is_business_day(Date::ymd(2001, 9, 11)));
+    assert!(!day_of.is_business_day(Date::ymd(2001, 9, 12)));
+    assert!(day_of.is_business_day(Date::ymd(2001, 9, 13)));
+
+    // Moved and extra bank holidays are announced too.
+    let jubilee = Date::ymd(2022, 6, 2);
+    let before = AnyCalendar::from(UKMarket::Exchange).as_of(Date::ymd(2020, 1, 1));
+    assert!(before.is_business_day(jubilee));
+    assert!(!UK_EXCHANGE
+        .as_of(Date::ymd(2021, 1, 1))
+        .is_business_day(jubilee));
+}
+
+#[test]
+fn moved_bank_holidays_keep_their_usual_day_until_announced() {
+    let d = Date::ymd;
+    // Usual day, the day it moved to, and when the move was announced.
+    let moves = [
+        (d(2012, 5, 28), d(2012, 6, 4), d(2010, 1, 5)),
+        (d(2020, 5, 4), d(2020, 5, 8), d(2019, 6, 7)),
+        (d(2022, 5, 30), d(2022, 6, 2), d(2020, 11, 12)),
+    ];
+    for (usual, moved, announced) in moves {
+        let before = UK_EXCHANGE.as_of(announced.add_days(-1));
+        assert!(!before.is_business_day(usual), "{usual:?}");
+        assert!(before.is_business_day(moved), "{moved:?}");
+
+        let after = UK_EXCHANGE.as_of(announced);
+        assert!(after.is_business_day(usual), "{usual:?}");
+        assert!(!after.is_business_day(moved), "{moved:?}");
+
+        assert!(UK_EXCHANGE.is_business_day(usual));
+        assert!(!UK_EXCHANGE.is_business_day(moved));
+    }
+    assert!(!UK_EXCHANGE
+        .as_of(d(2019, 1, 1))
+        .is_business_day(d(2020, 5, 4)));
+    assert!(!UK_EXCHANGE
+        .as_of(d(2009, 1, 1))
+        .is_business_day(d(2012, 5, 28)));
+}
+
+#[test]
+fn juneteenth_was_not_known_before_it_was_signed_into_law() {
+    let first_observed = Date::ymd(2021, 6, 18);
+    for cal in [&US_SETTLEMENT, &US_FED] {
+        assert!(cal
+            .as_of(Date::ymd(2021, 6, 16))
+            .is_business_day(first_observed));
+        assert!(!cal
+            .as_of(Date::ymd(2021, 6, 17))
+            .is_business_day(first_observed));
+    }
+}
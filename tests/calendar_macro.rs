@@ -0,0 +1,63 @@
+use business_calendar::dsl::{self, fixed, nth, one_off};
+use business_calendar::{calendar, Calendar, Date, HolidayCategory};
+use chrono::Weekday;
+
+calendar! {
+    /// A Friday/Saturday weekend exchange.
+    static GULF = "Gulf Exchange", weekend(Fri, Sat) {
+        "New Year's Day" => fixed(1, 1),
+        "National Day" => fixed(12, 2).since(1971),
+        "National Day Holiday" => relative("National Day", 1, RelativeCondition::Weekday),
+        "Exchange Outage" => one_off(2024, 4, 16).with_category(Unscheduled),
+    }
+}
+
+calendar! {
+    pub static LOCAL = "Local" {
+        // Relies on the default Saturday/Sunday weekend.
+        "Founders' Day" => last(10, Mon).between(1950, 1999),
+        "Founders' Day" => nth(10, Mon, 2).since(2000),
+        "Harvest Monday" => easter(0).until(1980),
+        "Leap Day" => fixed(2, 29),
+    }
+}
+
+#[test]
+fn macro_builds_a_static_calendar() {
+    assert_eq!(GULF.name, "Gulf Exchange");
+    assert_eq!(GULF.weekend, [Weekday::Fri, Weekday::Sat]);
+    assert_eq!(GULF.rules.len(), 4);
+    assert!(GULF.is_business_day(Date::ymd(2025, 1, 5))); // Sunday
+    assert!(!GULF.is_business_day(Date::ymd(2025, 1, 3))); // Friday
+
+    // 2 Dec 2025 is a Tuesday, so the day after is closed too.
+    assert!(!GULF.is_business_day(Date::ymd(2025, 12, 2)));
+    assert!(!GULF.is_business_day(Date::ymd(2025, 12, 3)));
+
+    let outage = GULF.explain(Date::ymd(2024, 4, 16));
+    assert_eq!(outage.holidays[0].category, HolidayCategory::Unscheduled);
+}
+
+#[test]
+fn year_ranges_and_defaults() {
+    assert_eq!(LOCAL.weekend, [Weekday::Sat, Weekday::Sun]);
+    assert!(!LOCAL.is_business_day(Date::ymd(1999, 10, 25)));
+    assert!(LOCAL.is_business_day(Date::ymd(2000, 10, 30)));
+    assert!(!LOCAL.is_business_day(Date::ymd(2000, 10, 9)));
+    assert!(!LOCAL.is_business_day(Date::ymd(2024, 2, 29)));
+}
+
+#[test]
+#[should_panic(expected = "overlapping year ranges")]
+fn validate_rejects_overlapping_ranges() {
+    dsl::validate(&[
+        fixed(5, 1).until(2000).named("Labour Day"),
+        nth(5, Weekday::Mon, 1).since(2000).named("Labour Day"),
+    ]);
+}
+
+#[test]
+#[should_panic(expected = "impossible one-off date")]
+fn validate_rejects_impossible_dates() {
+    dsl::validate(&[one_off(2023, 2, 29).named("Leap Day")]);
+}
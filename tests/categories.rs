@@ -0,0 +1,71 @@
+use business_calendar::markets::uk::{UKMarket, UnitedKingdom};
+use business_calendar::markets::us::{USMarket, UnitedStates};
+use business_calendar::{Calendar, Date, HolidayCategories, HolidayCategory};
+
+#[test]
+fn rules_carry_categories() {
+    let nyse = UnitedStates::new(USMarket::NYSE);
+    let e = nyse.explain(Date::ymd(2012, 10, 29));
+    assert_eq!(e.holidays[0].category, HolidayCategory::Unscheduled);
+    let e = nyse.explain(Date::ymd(2018, 12, 5));
+    assert_eq!(e.holidays[0].category, HolidayCategory::SpecialOneOff);
+    let e = nyse.explain(Date::ymd(2025, 12, 25));
+    assert_eq!(e.holidays[0].category, HolidayCategory::Statutory);
+
+    let uk = UnitedKingdom::new(UKMarket::Exchange);
+    let e = uk.explain(Date::ymd(2012, 6, 4));
+    assert_eq!(e.holidays[0].name, "Spring Bank Holiday");
+    assert_eq!(e.holidays[0].category, HolidayCategory::ObservedSubstitute);
+    let e = uk.explain(Date::ymd(2012, 6, 5));
+    assert_eq!(e.holidays[0].category, HolidayCategory::SpecialOneOff);
+}
+
+#[test]
+fn scheduled_view_ignores_unscheduled_closures() {
+    let nyse = UnitedStates::new(USMarket::NYSE);
+    let scheduled = nyse.with_categories(HolidayCategories::SCHEDULED);
+
+    for d in [(2001, 9, 11), (2001, 9, 14), (2012, 10, 29), (2012, 10, 30)] {
+        let date = Date::ymd(d.0, d.1, d.2);
+        assert!(!nyse.is_business_day(date));
+        assert!(scheduled.is_business_day(date));
+    }
+    // Planned closures and weekends are still honoured.
+    assert!(!scheduled.is_business_day(Date::ymd(2018, 12, 5)));
+    assert!(!scheduled.is_business_day(Date::ymd(2025, 12, 25)));
+    assert!(!scheduled.is_business_day(Date::ymd(2012, 10, 28)));
+}
+
+#[test]
+fn statutory_only_view() {
+    let uk = UnitedKingdom::new(UKMarket::Exchange);
+    let statutory = uk.with_categories(HolidayCategory::Statutory.into());
+
+    // Jubilee extra day and coronation reopen; the moved Spring Bank Holiday too.
+    assert!(statutory.is_business_day(Date::ymd(2022, 6, 3)));
+    assert!(statutory.is_business_day(Date::ymd(2023, 5, 8)));
+    assert!(statutory.is_business_day(Date::ymd(2022, 6, 2)));
+    assert!(!statutory.is_business_day(Date::ymd(2022, 12, 26)));
+
+    let next = statutory.next_holiday(Date::ymd(2022, 6, 1)).unwrap();
+    assert_eq!(next.name, "Summer Bank Holiday");
+    assert_eq!(next.observed, Date::ymd(2022, 8, 29));
+
+    let e = statutory.explain(Date::ymd(2022, 6, 3));
+    assert!(e.business_day);
+    assert!(e.holidays.is_empty());
+}
+
+#[test]
+fn category_sets() {
+    let set = HolidayCategories::NONE
+        .with(HolidayCategory::Statutory)
+        .with(HolidayCategory::Unscheduled);
+    assert!(set.contains(HolidayCategory::Statutory));
+    assert!(!set.contains(HolidayCategory::SpecialOneOff));
+    assert!(!set
+        .without(HolidayCategory::Unscheduled)
+        .contains(HolidayCategory::Unscheduled));
+    assert!(!HolidayCategories::SCHEDULED.contains(HolidayCategory::Unscheduled));
+    assert!(HolidayCategories::ALL.contains(HolidayCategory::Unscheduled));
+}
@@ -0,0 +1,44 @@
+//! Calendars shipped with the crate, shared by the test files that check
+//! every one of them.
+
+use business_calendar::markets::eu::EU_TARGET;
+use business_calendar::markets::fr::exchange::FR_EXCHANGE;
+use business_calendar::markets::fr::settlement::FR_SETTLEMENT;
+use business_calendar::markets::uk::{UK_EXCHANGE, UK_METALS, UK_SETTLEMENT};
+use business_calendar::markets::us::{
+    fed::US_FED, gov_bond::US_GOVERNMENT_BOND, libor_impact::US_LIBOR_IMPACT, nerc::US_NERC,
+    nyse::US_NYSE, settlement::US_SETTLEMENT, sofr::US_SOFR,
+};
+use business_calendar::{DerivedCalendar, StaticCalendar};
+
+/// Data file, name of the generated static and the static itself.
+pub type Shipped = (&'static str, &'static str, &'static StaticCalendar<'static>);
+pub type ShippedDerived = (&'static str, &'static str, &'static DerivedCalendar);
+
+macro_rules! shipped {
+    ($($path:literal => $calendar:ident),* $(,)?) => {
+        [$(($path, stringify!($calendar), &$calendar)),*]
+    };
+}
+
+/// Every data file with its own rules. A new data file must be added here
+/// (or to [`SHIPPED_DERIVED`]) for the tests to pass.
+pub const SHIPPED: [Shipped; 10] = shipped![
+    "us/settlement.toml" => US_SETTLEMENT,
+    "us/nyse.toml" => US_NYSE,
+    "us/gov_bond.toml" => US_GOVERNMENT_BOND,
+    "us/nerc.toml" => US_NERC,
+    "us/fed.toml" => US_FED,
+    "uk/settlement.toml" => UK_SETTLEMENT,
+    "uk/exchange.toml" => UK_EXCHANGE,
+    "fr/settlement.toml" => FR_SETTLEMENT,
+    "fr/exchange.toml" => FR_EXCHANGE,
+    "eu/target.toml" => EU_TARGET,
+];
+
+/// Data files with a `base`.
+pub const SHIPPED_DERIVED: [ShippedDerived; 3] = shipped![
+    "us/libor_impact.toml" => US_LIBOR_IMPACT,
+    "us/sofr.toml" => US_SOFR,
+    "uk/metals.toml" => UK_METALS,
+];
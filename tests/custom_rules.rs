@@ -0,0 +1,100 @@
+use business_calendar::rules::{FnRule, HolidayRule, HolidayRuleLike, NamedRule, YearRange};
+use business_calendar::static_calendar::StaticCalendar;
+use business_calendar::{Calendar, Date};
+use chrono::Weekday;
+
+/// Last business day of the year, as a hand-written rule.
+#[derive(Debug)]
+struct LastWeekdayOfYear;
+
+impl HolidayRuleLike for LastWeekdayOfYear {
+    fn is_holiday(&self, date: Date) -> bool {
+        self.holidays_in_year(date.year()).contains(&date)
+    }
+
+    fn holidays_in_year(&self, year: i32) -> Vec<Date> {
+        let mut d = Date::ymd(year, 12, 31);
+        while matches!(d.weekday(), Weekday::Sat | Weekday::Sun) {
+            d = d.add_days(-1);
+        }
+        vec![d]
+    }
+}
+
+static LAST_WEEKDAY: LastWeekdayOfYear = LastWeekdayOfYear;
+static FIRST_MONDAYS: FnRule = FnRule::new(|d| d.day() <= 7 && d.weekday() == Weekday::Mon);
+
+static RULES: &[NamedRule] = &[
+    NamedRule::new(
+        "Christmas Day",
+        HolidayRule::Fixed {
+            month: 12,
+            day: 25,
+            years: YearRange::always(),
+        },
+    ),
+    NamedRule::new("Year End", HolidayRule::Custom(&LAST_WEEKDAY)),
+    NamedRule::new("First Monday", HolidayRule::Custom(&FIRST_MONDAYS)),
+];
+
+static MIXED: StaticCalendar = StaticCalendar::new("Mixed", [Weekday::Sat, Weekday::Sun], RULES);
+
+#[test]
+fn built_in_and_custom_rules_mix() {
+    assert!(!MIXED.is_business_day(Date::ymd(2025, 12, 25)));
+    // 31 Dec 2022 was a Saturday.
+    assert!(!MIXED.is_business_day(Date::ymd(2022, 12, 30)));
+    assert!(!MIXED.is_business_day(Date::ymd(2025, 12, 31)));
+    assert!(!MIXED.is_business_day(Date::ymd(2025, 3, 3)));
+    assert!(MIXED.is_business_day(Date::ymd(2025, 3, 10)));
+
+    let e = MIXED.explain(Date::ymd(2025, 12, 31));
+    assert_eq!(e.holidays[0].name, "Year End");
+    assert_eq!(e.holidays[0].observed, Date::ymd(2025, 12, 31));
+}
+
+#[test]
+fn custom_rules_with_several_dates_a_year() {
+    let holidays: Vec<_> = (1..=12)
+        .map(|m| {
+            let month_before = Date::ymd(2025, m, 1).add_days(-1);
+            MIXED.next_holiday(month_before).unwrap().observed
+        })
+        .collect();
+    // The first Monday of every month.
+    assert_eq!(holidays[0], Date::ymd(2025, 1, 6));
+    assert_eq!(holidays[8], Date::ymd(2025, 9, 1));
+    assert_eq!(FIRST_MONDAYS.holidays_in_year(2025).len(), 12);
+}
+
+#[test]
+fn built_in_rules_implement_the_trait() {
+    let rule = HolidayRule::WeekendAdjustedFixed {
+        month: 7,
+        day: 4,
+        years: YearRange::always(),
+    };
+    let rule: &dyn HolidayRuleLike = &rule;
+    assert!(rule.is_holiday(Date::ymd(2020, 7, 3)));
+    assert_eq!(
+        rule.holidays_in_year(2020),
+        [Date::ymd(2020, 7, 3), Date::ymd(2020, 7, 4)]
+    );
+}
+
+#[test]
+fn holidays_in_year_stay_in_the_year() {
+    let new_year = HolidayRule::WeekendAdjustedFixed {
+        month: 1,
+        day: 1,
+        years: YearRange::always(),
+    };
+    // 1 January 2022 was a Saturday; 31 December 2021 is not a holiday.
+    let dates = new_year.holidays_in_year(2022);
+    assert_eq!(dates, [Date::ymd(2022, 1, 1)]);
+    assert!(dates.iter().all(|&d| new_year.is_holiday(d)));
+    assert!(!HolidayRuleLike::is_holiday(
+        &new_year,
+        Date::ymd(2021, 12, 31)
+    ));
+}
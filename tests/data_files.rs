@@ -0,0 +1,335 @@
+use std::fs;
+use std::path::Path;
+
+use business_calendar::markets::eu::EU_TARGET;
+use business_calendar::markets::fr::exchange::FR_EXCHANGE;
+use business_calendar::markets::fr::settlement::FR_SETTLEMENT;
+use business_calendar::markets::uk::{UK_EXCHANGE, UK_SETTLEMENT};
+use business_calendar::markets::us::{
+    fed::US_FED, gov_bond::US_GOVERNMENT_BOND, nerc::US_NERC, nyse::US_NYSE,
+    settlement::US_SETTLEMENT,
+};
+use business_calendar::{Calendar, Date, DateRange, StaticCalendar};
+
+mod common;
+
+use common::{SHIPPED, SHIPPED_DERIVED};
+
+fn data_dir() -> String {
+    format!("{}/data", env!("CARGO_MANIFEST_DIR"))
+}
+
+fn data_file(path: &str) -> String {
+    fs::read_to_string(format!("{}/{path}", data_dir())).unwrap()
+}
+
+fn holidays_in(calendar: &StaticCalendar, year: i32) -> Vec<Date> {
+    calendar
+        .holidays(DateRange::new(
+            Date::ymd(year, 1, 1)..=Date::ymd(year, 12, 31),
+        ))
+        .collect()
+}
+
+#[test]
+fn generated_tables_match_their_data_files() {
+    for (path, _, calendar) in SHIPPED {
+        let text = data_file(path);
+        assert!(
+            text.contains(&format!("name = {:?}\n", calendar.name)),
+            "{path}"
+        );
+        let rules = text.lines().filter(|l| *l == "[[rules]]").count();
+        assert_eq!(rules, calendar.rules.len(), "{path}");
+        for rule in calendar.rules {
+            assert!(
+                text.contains(&format!("name = {:?}\n", rule.name)),
+                "{path}: {}",
+                rule.name
+            );
+        }
+    }
+}
+
+#[test]
+fn derived_tables_match_their_data_files() {
+    for (path, _, calendar) in SHIPPED_DERIVED {
+        let text = data_file(path);
+        assert!(
+            text.contains(&format!("name = {:?}\n", calendar.name())),
+            "{path}"
+        );
+        let (base_path, ..) = SHIPPED
+            .into_iter()
+            .find(|(_, _, base)| std::ptr::eq(*base, calendar.base()))
+            .unwrap();
+        let base = base_path
+            .rsplit('/')
+            .next()
+            .unwrap()
+            .trim_end_matches(".toml");
+        assert!(text.contains(&format!("base = {base:?}\n")), "{path}");
+
+        let base_rules = calendar.base().rules.len();
+        let overlays = text.lines().filter(|l| *l == "[[overlay]]").count();
+        assert_eq!(calendar.rules().len(), base_rules + overlays, "{path}");
+    }
+}
+
+#[test]
+fn every_data_file_is_shipped() {
+    let mut files = Vec::new();
+    for market in fs::read_dir(data_dir()).unwrap() {
+        let market = market.unwrap().path();
+        if !market.is_dir() {
+            continue;
+        }
+        for file in fs::read_dir(&market).unwrap() {
+            let file = file.unwrap().path();
+            if file.extension().is_some_and(|ext| ext == "toml") {
+                let relative = file.strip_prefix(Path::new(&data_dir())).unwrap();
+                files.push(relative.to_str().unwrap().replace('\\', "/"));
+            }
+        }
+    }
+    files.sort();
+
+    let names = SHIPPED.iter().map(|(path, name, _)| (path, name));
+    let names = names.chain(SHIPPED_DERIVED.iter().map(|(path, name, _)| (path, name)));
+    let mut shipped: Vec<String> = names.clone().map(|(path, _)| path.to_string()).collect();
+    shipped.sort();
+    assert_eq!(files, shipped);
+
+    for (path, name) in names {
+        assert!(
+            data_file(path).contains(&format!("static = {name:?}\n")),
+            "{path} does not generate {name}"
+        );
+    }
+}
+
+#[test]
+fn generated_tables_resolve_to_the_published_dates() {
+    let d = Date::ymd;
+    let samples: [(&StaticCalendar, i32, Vec<Date>); 14] = [
+        (
+            &US_SETTLEMENT,
+            2024,
+            vec![
+                d(2024, 1, 1),
+                d(2024, 1, 15),
+                d(2024, 2, 19),
+                d(2024, 5, 27),
+                d(2024, 6, 19),
+                d(2024, 7, 4),
+                d(2024, 9, 2),
+                d(2024, 10, 14),
+                d(2024, 11, 11),
+                d(2024, 11, 28),
+                d(2024, 12, 25),
+            ],
+        ),
+        (
+            &US_NYSE,
+            2024,
+            vec![
+                d(2024, 1, 1),
+                d(2024, 1, 15),
+                d(2024, 2, 19),
+                d(2024, 3, 29),
+                d(2024, 5, 27),
+                d(2024, 6, 19),
+                d(2024, 7, 4),
+                d(2024, 9, 2),
+                d(2024, 11, 28),
+                d(2024, 12, 25),
+            ],
+        ),
+        // Hurricane Sandy.
+        (
+            &US_NYSE,
+            2012,
+            vec![
+                d(2012, 1, 2),
+                d(2012, 1, 16),
+                d(2012, 2, 20),
+                d(2012, 4, 6),
+                d(2012, 5, 28),
+                d(2012, 7, 4),
+                d(2012, 9, 3),
+                d(2012, 10, 29),
+                d(2012, 10, 30),
+                d(2012, 11, 22),
+                d(2012, 12, 25),
+            ],
+        ),
+        (
+            &US_GOVERNMENT_BOND,
+            2024,
+            vec![
+                d(2024, 1, 1),
+                d(2024, 1, 15),
+                d(2024, 2, 19),
+                d(2024, 3, 29),
+                d(2024, 5, 27),
+                d(2024, 6, 19),
+                d(2024, 7, 4),
+                d(2024, 9, 2),
+                d(2024, 10, 14),
+                d(2024, 11, 11),
+                d(2024, 11, 28),
+                d(2024, 12, 25),
+            ],
+        ),
+        (
+            &US_NERC,
+            2024,
+            vec![
+                d(2024, 1, 1),
+                d(2024, 5, 27),
+                d(2024, 7, 4),
+                d(2024, 9, 2),
+                d(2024, 11, 28),
+                d(2024, 12, 25),
+            ],
+        ),
+        (
+            &US_FED,
+            2024,
+            vec![
+                d(2024, 1, 1),
+                d(2024, 1, 15),
+                d(2024, 2, 19),
+                d(2024, 5, 27),
+                d(2024, 6, 19),
+                d(2024, 7, 4),
+                d(2024, 9, 2),
+                d(2024, 10, 14),
+                d(2024, 11, 11),
+                d(2024, 11, 28),
+                d(2024, 12, 25),
+            ],
+        ),
+        (
+            &UK_SETTLEMENT,
+            2024,
+            vec![
+                d(2024, 1, 1),
+                d(2024, 3, 29),
+                d(2024, 4, 1),
+                d(2024, 5, 6),
+                d(2024, 5, 27),
+                d(2024, 8, 26),
+                d(2024, 12, 25),
+                d(2024, 12, 26),
+            ],
+        ),
+        // Diamond Jubilee.
+        (
+            &UK_SETTLEMENT,
+            2012,
+            vec![
+                d(2012, 1, 2),
+                d(2012, 4, 6),
+                d(2012, 4, 9),
+                d(2012, 5, 7),
+                d(2012, 6, 4),
+                d(2012, 6, 5),
+                d(2012, 8, 27),
+                d(2012, 12, 25),
+                d(2012, 12, 26),
+            ],
+        ),
+        (
+            &UK_EXCHANGE,
+            2024,
+            vec![
+                d(2024, 1, 1),
+                d(2024, 3, 29),
+                d(2024, 4, 1),
+                d(2024, 5, 6),
+                d(2024, 5, 27),
+                d(2024, 8, 26),
+                d(2024, 12, 25),
+                d(2024, 12, 26),
+            ],
+        ),
+        // Platinum Jubilee and the Queen's funeral.
+        (
+            &UK_EXCHANGE,
+            2022,
+            vec![
+                d(2022, 4, 15),
+                d(2022, 4, 18),
+                d(2022, 5, 2),
+                d(2022, 6, 2),
+                d(2022, 6, 3),
+                d(2022, 8, 29),
+                d(2022, 9, 19),
+                d(2022, 12, 26),
+            ],
+        ),
+        (
+            &FR_SETTLEMENT,
+            2024,
+            vec![
+                d(2024, 1, 1),
+                d(2024, 4, 1),
+                d(2024, 5, 1),
+                d(2024, 5, 8),
+                d(2024, 5, 9),
+                d(2024, 5, 20),
+                d(2024, 8, 15),
+                d(2024, 11, 1),
+                d(2024, 11, 11),
+                d(2024, 12, 25),
+            ],
+        ),
+        (
+            &FR_EXCHANGE,
+            2024,
+            vec![
+                d(2024, 1, 1),
+                d(2024, 3, 29),
+                d(2024, 4, 1),
+                d(2024, 5, 1),
+                d(2024, 12, 24),
+                d(2024, 12, 25),
+                d(2024, 12, 26),
+                d(2024, 12, 31),
+            ],
+        ),
+        (
+            &EU_TARGET,
+            2024,
+            vec![
+                d(2024, 1, 1),
+                d(2024, 3, 29),
+                d(2024, 4, 1),
+                d(2024, 5, 1),
+                d(2024, 12, 25),
+                d(2024, 12, 26),
+            ],
+        ),
+        (
+            &EU_TARGET,
+            2022,
+            vec![d(2022, 4, 15), d(2022, 4, 18), d(2022, 12, 26)],
+        ),
+    ];
+    // Every shipped calendar is sampled.
+    for (path, _, calendar) in SHIPPED {
+        assert!(
+            samples.iter().any(|(c, ..)| std::ptr::eq(*c, calendar)),
+            "{path}"
+        );
+    }
+    for (calendar, year, expected) in samples {
+        assert_eq!(
+            holidays_in(calendar, year),
+            expected,
+            "{} {year}",
+            calendar.name
+        );
+    }
+}
@@ -0,0 +1,113 @@
+use std::collections::HashSet;
+use std::ops::Bound;
+
+use business_calendar::markets::us::{USMarket, UnitedStates};
+use business_calendar::{BusinessDays, Calendar, Date, DateRange};
+
+#[test]
+fn range_bounds() {
+    let a = Date::ymd(2025, 1, 1);
+    let b = Date::ymd(2025, 1, 10);
+
+    let inclusive = DateRange::new(a..=b);
+    assert_eq!(inclusive, DateRange::inclusive(a, b));
+    assert_eq!(inclusive.len(), 10);
+    assert!(inclusive.contains(b));
+
+    let exclusive = DateRange::exclusive(a, b);
+    assert_eq!(exclusive.end(), Date::ymd(2025, 1, 9));
+    assert!(!exclusive.contains(b));
+
+    let open_start = DateRange::new((Bound::Excluded(a), Bound::Included(b)));
+    assert_eq!(open_start.start(), Date::ymd(2025, 1, 2));
+
+    assert!(DateRange::exclusive(a, a).is_empty());
+    assert_eq!(DateRange::exclusive(a, a).len(), 0);
+}
+
+#[test]
+fn empty_ranges_are_equal() {
+    let a = Date::ymd(2025, 1, 1);
+    let b = Date::ymd(2025, 1, 10);
+
+    let empties = [
+        DateRange::empty(),
+        DateRange::inclusive(b, a),
+        DateRange::exclusive(a, a),
+        DateRange::new(b..a),
+        DateRange::inclusive(a, a).intersection(&DateRange::inclusive(b, b)),
+    ];
+    for range in empties {
+        assert!(range.is_empty());
+        assert_eq!(range, DateRange::empty());
+    }
+    assert_eq!(empties.iter().collect::<HashSet<_>>().len(), 1);
+}
+
+#[test]
+fn range_intersection() {
+    let jan = DateRange::new(Date::ymd(2025, 1, 1)..Date::ymd(2025, 2, 1));
+    let mid = DateRange::inclusive(Date::ymd(2025, 1, 20), Date::ymd(2025, 2, 10));
+    let both = jan.intersection(&mid);
+    assert_eq!(
+        both,
+        DateRange::inclusive(Date::ymd(2025, 1, 20), Date::ymd(2025, 1, 31))
+    );
+
+    let feb = DateRange::inclusive(Date::ymd(2025, 2, 1), Date::ymd(2025, 2, 28));
+    assert!(jan.intersection(&feb).is_empty());
+}
+
+#[test]
+fn business_days_skip_weekends_and_holidays() {
+    let cal = UnitedStates::new(USMarket::Settlement);
+    // Sat 28 Jun 2025 .. Sun 6 Jul 2025
+    let range = DateRange::inclusive(Date::ymd(2025, 6, 28), Date::ymd(2025, 7, 6));
+
+    let days: Vec<Date> = cal.business_days(range).collect();
+    assert_eq!(
+        days,
+        vec![
+            Date::ymd(2025, 6, 30),
+            Date::ymd(2025, 7, 1),
+            Date::ymd(2025, 7, 2),
+            Date::ymd(2025, 7, 3),
+        ]
+    );
+
+    let reversed: Vec<Date> = cal.business_days(range).rev().collect();
+    assert_eq!(reversed, days.iter().rev().copied().collect::<Vec<_>>());
+
+    // Meeting in the middle from both ends yields each day once.
+    let mut it = cal.business_days(range);
+    assert_eq!(it.next(), Some(Date::ymd(2025, 6, 30)));
+    assert_eq!(it.next_back(), Some(Date::ymd(2025, 7, 3)));
+    assert_eq!(it.next(), Some(Date::ymd(2025, 7, 1)));
+    assert_eq!(it.next_back(), Some(Date::ymd(2025, 7, 2)));
+    assert_eq!(it.next(), None);
+    assert_eq!(it.next_back(), None);
+}
+
+#[test]
+fn holidays_in_year() {
+    let cal = UnitedStates::new(USMarket::NYSE);
+    let year = DateRange::new(Date::ymd(2025, 1, 1)..Date::ymd(2026, 1, 1));
+
+    let holidays: Vec<Date> = cal.holidays(year).collect();
+    assert_eq!(holidays.len(), 10);
+    assert_eq!(holidays.first(), Some(&Date::ymd(2025, 1, 1)));
+    assert_eq!(
+        cal.holidays(year).next_back(),
+        Some(Date::ymd(2025, 12, 25))
+    );
+    assert_eq!(cal.business_days(year).count(), 365 - 104 - 10);
+}
+
+#[test]
+fn iterators_work_through_trait_objects() {
+    let cal = UnitedStates::new(USMarket::Settlement);
+    let dyn_cal: &dyn Calendar = &cal;
+    let week = DateRange::inclusive(Date::ymd(2025, 6, 30), Date::ymd(2025, 7, 6));
+    assert_eq!(BusinessDays::new(dyn_cal, week).count(), 4);
+    assert_eq!(BusinessDays::new(dyn_cal, DateRange::empty()).count(), 0);
+}
@@ -0,0 +1,61 @@
+use business_calendar::rules::{DateTable, HolidayRule, NamedRule};
+use business_calendar::static_calendar::StaticCalendar;
+use business_calendar::{Calendar, Date};
+use chrono::Weekday;
+
+// Spring Festival closures of the Shanghai Stock Exchange (weekdays only).
+static SPRING_FESTIVAL: DateTable = DateTable::new(&[
+    20230123, 20230124, 20230125, 20230126, 20230127, //
+    20240209, 20240212, 20240213, 20240214, 20240215, 20240216, //
+    20250128, 20250129, 20250130, 20250131, 20250203, 20250204,
+]);
+
+static RULES: &[NamedRule] = &[NamedRule::new(
+    "Spring Festival",
+    HolidayRule::Dates(SPRING_FESTIVAL),
+)];
+
+static SSE: StaticCalendar = StaticCalendar::new("SSE", [Weekday::Sat, Weekday::Sun], RULES);
+
+#[test]
+fn lookups_use_the_table() {
+    assert_eq!(SPRING_FESTIVAL.len(), 17);
+    assert!(SPRING_FESTIVAL.contains(Date::ymd(2024, 2, 12)));
+    assert!(!SPRING_FESTIVAL.contains(Date::ymd(2024, 2, 10)));
+    assert!(!SPRING_FESTIVAL.contains(Date::ymd(-5, 1, 1)));
+    let days: Vec<_> = SPRING_FESTIVAL.in_year(2023).collect();
+    assert_eq!(days.first(), Some(&Date::ymd(2023, 1, 23)));
+    assert_eq!(days.len(), 5);
+    assert_eq!(SPRING_FESTIVAL.in_year(2026).count(), 0);
+}
+
+#[test]
+fn calendar_sees_every_table_date() {
+    assert!(!SSE.is_business_day(Date::ymd(2025, 2, 4)));
+    assert!(SSE.is_business_day(Date::ymd(2025, 2, 5)));
+    assert_eq!(
+        SSE.explain(Date::ymd(2025, 1, 31)).holidays[0].name,
+        "Spring Festival"
+    );
+
+    let mut d = Date::ymd(2024, 1, 1);
+    let mut seen = Vec::new();
+    while let Some(occ) = SSE.next_holiday(d) {
+        seen.push(occ.observed);
+        d = occ.observed;
+    }
+    assert_eq!(seen.len(), 12);
+    assert_eq!(seen.last(), Some(&Date::ymd(2025, 2, 4)));
+}
+
+#[test]
+#[should_panic(expected = "strictly ascending")]
+fn unsorted_tables_are_rejected() {
+    DateTable::new(&[20250130, 20250129]);
+}
+
+#[test]
+#[should_panic(expected = "invalid")]
+fn invalid_dates_are_rejected() {
+    DateTable::new(&[20250229]);
+}
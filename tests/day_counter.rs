@@ -0,0 +1,180 @@
+use business_calendar::markets::us::{USMarket, UnitedStates};
+use business_calendar::{Date, DayCounter};
+
+fn assert_close(actual: f64, expected: f64) {
+    assert!(
+        (actual - expected).abs() < 1e-10,
+        "expected {expected}, got {actual}"
+    );
+}
+
+#[test]
+fn actual_conventions() {
+    let start = Date::ymd(2024, 1, 1);
+    let end = Date::ymd(2025, 1, 1);
+
+    assert_eq!(DayCounter::Actual360.day_count(start, end), 366);
+    assert_close(
+        DayCounter::Actual360.year_fraction(start, end),
+        366.0 / 360.0,
+    );
+    assert_close(
+        DayCounter::Actual365Fixed.year_fraction(start, end),
+        366.0 / 365.0,
+    );
+    assert_close(
+        DayCounter::Actual36525.year_fraction(start, end),
+        366.0 / 365.25,
+    );
+    assert_close(
+        DayCounter::Actual360.year_fraction(end, start),
+        -366.0 / 360.0,
+    );
+}
+
+#[test]
+fn thirty_360_family_end_of_month_handling() {
+    // 31 Aug to 28 Feb (non-leap year end of February).
+    let start = Date::ymd(2006, 8, 31);
+    let end = Date::ymd(2007, 2, 28);
+
+    assert_eq!(DayCounter::Thirty360BondBasis.day_count(start, end), 178);
+    assert_eq!(DayCounter::Thirty360Eurobond.day_count(start, end), 178);
+    assert_eq!(DayCounter::Thirty360German.day_count(start, end), 180);
+    assert_eq!(
+        DayCounter::Thirty360Isda { termination: None }.day_count(start, end),
+        180
+    );
+    assert_eq!(
+        DayCounter::Thirty360Isda {
+            termination: Some(end)
+        }
+        .day_count(start, end),
+        178
+    );
+
+    // 30 Jan to 31 Mar: Bond Basis only rolls the 31st when the start is the 30th/31st.
+    let start = Date::ymd(2007, 1, 30);
+    let end = Date::ymd(2007, 3, 31);
+    assert_eq!(DayCounter::Thirty360BondBasis.day_count(start, end), 60);
+    let start = Date::ymd(2007, 1, 29);
+    assert_eq!(DayCounter::Thirty360BondBasis.day_count(start, end), 62);
+    assert_eq!(DayCounter::Thirty360Eurobond.day_count(start, end), 61);
+}
+
+#[test]
+fn actual_actual_isda_memo_examples() {
+    // Examples from the ISDA 1998 memo "EMU and Market Conventions".
+    struct Case {
+        start: Date,
+        end: Date,
+        ref_start: Date,
+        ref_end: Date,
+        isda: f64,
+        icma: f64,
+        afb: f64,
+    }
+
+    let cases = [
+        // Regular period
+        Case {
+            start: Date::ymd(2003, 11, 1),
+            end: Date::ymd(2004, 5, 1),
+            ref_start: Date::ymd(2003, 11, 1),
+            ref_end: Date::ymd(2004, 5, 1),
+            isda: 0.497724380567,
+            icma: 0.500000000000,
+            afb: 0.497267759563,
+        },
+        // Short first period
+        Case {
+            start: Date::ymd(1999, 2, 1),
+            end: Date::ymd(1999, 7, 1),
+            ref_start: Date::ymd(1998, 7, 1),
+            ref_end: Date::ymd(1999, 7, 1),
+            isda: 0.410958904110,
+            icma: 0.410958904110,
+            afb: 0.410958904110,
+        },
+        // Long first period
+        Case {
+            start: Date::ymd(2002, 8, 15),
+            end: Date::ymd(2003, 7, 15),
+            ref_start: Date::ymd(2003, 1, 15),
+            ref_end: Date::ymd(2003, 7, 15),
+            isda: 0.915068493151,
+            icma: 0.915760869565,
+            afb: 0.915068493151,
+        },
+        // Short final period
+        Case {
+            start: Date::ymd(2000, 1, 30),
+            end: Date::ymd(2000, 6, 30),
+            ref_start: Date::ymd(2000, 1, 30),
+            ref_end: Date::ymd(2000, 7, 30),
+            isda: 0.415300546448,
+            icma: 0.417582417582,
+            afb: 0.415300546448,
+        },
+    ];
+
+    for c in &cases {
+        assert_close(
+            DayCounter::ActualActualIsda.year_fraction(c.start, c.end),
+            c.isda,
+        );
+        assert_close(
+            DayCounter::ActualActualIcma.year_fraction_with_reference(
+                c.start,
+                c.end,
+                c.ref_start,
+                c.ref_end,
+            ),
+            c.icma,
+        );
+        assert_close(
+            DayCounter::ActualActualAfb.year_fraction(c.start, c.end),
+            c.afb,
+        );
+    }
+}
+
+#[test]
+fn actual_actual_icma_reversed_dates_negate() {
+    let (start, end) = (Date::ymd(2000, 1, 30), Date::ymd(2000, 6, 30));
+    let (ref_start, ref_end) = (Date::ymd(2000, 1, 30), Date::ymd(2000, 7, 30));
+    let icma = DayCounter::ActualActualIcma;
+    assert_close(
+        icma.year_fraction_with_reference(end, start, ref_start, ref_end),
+        -icma.year_fraction_with_reference(start, end, ref_start, ref_end),
+    );
+    assert_close(
+        icma.year_fraction(end, start),
+        -icma.year_fraction(start, end),
+    );
+}
+
+#[test]
+#[should_panic(expected = "reference period 2000-07-30 to 2000-01-30 is empty or inverted")]
+fn actual_actual_icma_rejects_inverted_reference_period() {
+    DayCounter::ActualActualIcma.year_fraction_with_reference(
+        Date::ymd(2000, 1, 30),
+        Date::ymd(2000, 6, 30),
+        Date::ymd(2000, 7, 30),
+        Date::ymd(2000, 1, 30),
+    );
+}
+
+#[test]
+fn business_252_counts_calendar_business_days() {
+    let cal = UnitedStates::new(USMarket::Settlement);
+    let dc = DayCounter::Business252 { calendar: &cal };
+
+    // Mon 30 Jun 2025 to Mon 7 Jul 2025: Jun 30, Jul 1-3 (Jul 4 is a holiday).
+    let start = Date::ymd(2025, 6, 30);
+    let end = Date::ymd(2025, 7, 7);
+    assert_eq!(dc.day_count(start, end), 4);
+    assert_close(dc.year_fraction(start, end), 4.0 / 252.0);
+    assert_eq!(dc.day_count(end, start), -4);
+    assert_eq!(dc.name(), "Business/252");
+}
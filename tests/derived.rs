@@ -0,0 +1,174 @@
+use business_calendar::diff::diff;
+use business_calendar::markets::uk::{UKMarket, UnitedKingdom};
+use business_calendar::markets::us::gov_bond::US_GOVERNMENT_BOND;
+use business_calendar::markets::us::libor_impact::US_LIBOR_IMPACT;
+use business_calendar::markets::us::nyse::US_NYSE;
+use business_calendar::markets::us::settlement::US_SETTLEMENT;
+use business_calendar::markets::us::sofr::US_SOFR;
+use business_calendar::{
+    Calendar, Date, DateRange, DerivedCalendar, HolidayRule, NamedRule, RelativeCondition, RuleRef,
+    StaticCalendar, YearRange,
+};
+use chrono::Weekday;
+
+static NO_COLUMBUS_OR_VETERANS: DerivedCalendar = DerivedCalendar::new(
+    "US Settlement (no Columbus/Veterans Day)",
+    &US_SETTLEMENT,
+    &["Columbus Day", "Veterans Day"],
+    &[],
+    &[],
+);
+
+#[test]
+fn without_removes_named_rules() {
+    let years = DateRange::inclusive(Date::ymd(2020, 1, 1), Date::ymd(2025, 12, 31));
+    let diffs = diff(&US_SETTLEMENT, &NO_COLUMBUS_OR_VETERANS, years);
+    assert!(!diffs.is_empty());
+    assert!(diffs
+        .iter()
+        .all(|d| matches!(d.rules[0].name, "Columbus Day" | "Veterans Day")));
+
+    let runtime = US_SETTLEMENT
+        .without("Columbus Day")
+        .without("Veterans Day");
+    assert_eq!(runtime.rules().len(), NO_COLUMBUS_OR_VETERANS.rules().len());
+    assert!(runtime.is_business_day(Date::ymd(2025, 11, 11)));
+}
+
+#[test]
+fn replace_and_overlay() {
+    // Boxing Day instead of Christmas Day, plus a one-off closure.
+    let cal = US_SETTLEMENT
+        .replace(NamedRule::new(
+            "Christmas Day",
+            HolidayRule::Fixed {
+                month: 12,
+                day: 26,
+                years: YearRange::always(),
+            },
+        ))
+        .overlay(NamedRule::new(
+            "Office Move",
+            HolidayRule::OneOff {
+                year: 2025,
+                month: 3,
+                day: 14,
+            },
+        ))
+        .named("Custom");
+    assert!(cal.is_business_day(Date::ymd(2025, 12, 25)));
+    assert!(!cal.is_business_day(Date::ymd(2025, 12, 26)));
+    assert!(!cal.is_business_day(Date::ymd(2025, 3, 14)));
+    assert_eq!(cal.name(), "Custom");
+    assert_eq!(cal.rules().len(), US_SETTLEMENT.rules.len() + 1);
+}
+
+#[test]
+fn libor_impact_is_settlement_plus_an_overlay() {
+    // 4 July 2015 and 2020 were Saturdays; 2021 a Sunday.
+    for d in [
+        Date::ymd(2015, 7, 3),
+        Date::ymd(2020, 7, 3),
+        Date::ymd(2021, 7, 5),
+    ] {
+        assert!(!US_SETTLEMENT.is_business_day(d));
+        assert!(US_LIBOR_IMPACT.is_business_day(d), "{d:?}");
+    }
+    assert!(!US_LIBOR_IMPACT.is_business_day(Date::ymd(2009, 7, 3)));
+    assert!(!US_LIBOR_IMPACT.is_business_day(Date::ymd(2025, 7, 4)));
+
+    let years = DateRange::inclusive(Date::ymd(1990, 1, 1), Date::ymd(2040, 12, 31));
+    assert_eq!(diff(&US_SETTLEMENT, &US_LIBOR_IMPACT, years).len(), 8);
+}
+
+#[test]
+fn sofr_matches_government_bond() {
+    let years = DateRange::inclusive(Date::ymd(2000, 1, 1), Date::ymd(2030, 12, 31));
+    assert!(diff(&US_GOVERNMENT_BOND, &US_SOFR, years).is_empty());
+    assert_eq!(US_SOFR.base().name, "US Government Bond");
+}
+
+static INDEXED_RULES: [NamedRule; 3] = [
+    NamedRule::new(
+        "Founders' Day",
+        HolidayRule::OneOff {
+            year: 2025,
+            month: 3,
+            day: 3,
+        },
+    ),
+    NamedRule::new(
+        "Christmas Day",
+        HolidayRule::Fixed {
+            month: 12,
+            day: 25,
+            years: YearRange::always(),
+        },
+    ),
+    NamedRule::new(
+        "Christmas Eve",
+        HolidayRule::Relative {
+            base: RuleRef::Index(1),
+            offset: -1,
+            condition: RelativeCondition::Always,
+            years: YearRange::always(),
+        },
+    ),
+];
+static INDEXED: StaticCalendar =
+    StaticCalendar::new("Indexed", [Weekday::Sat, Weekday::Sun], &INDEXED_RULES);
+
+#[test]
+fn index_references_follow_the_base_table() {
+    let eve = Date::ymd(2025, 12, 24);
+    assert!(!INDEXED.is_business_day(eve));
+
+    // Removing an earlier rule shifts the table but not the reference.
+    let shifted = INDEXED.without("Founders' Day");
+    assert!(!shifted.is_business_day(eve));
+
+    // A reference to a replaced rule follows the replacement.
+    let moved = INDEXED.replace(NamedRule::new(
+        "Christmas Day",
+        HolidayRule::Fixed {
+            month: 12,
+            day: 26,
+            years: YearRange::always(),
+        },
+    ));
+    assert!(moved.is_business_day(eve));
+    assert!(!moved.is_business_day(Date::ymd(2025, 12, 25)));
+
+    // A reference to a removed rule matches nothing.
+    let removed = INDEXED.without("Christmas Day");
+    assert!(removed.is_business_day(eve));
+}
+
+#[test]
+fn views_are_named_apart_from_their_base() {
+    let no_columbus = US_SETTLEMENT.without("Columbus Day");
+    assert_eq!(no_columbus.name(), "US Settlement (modified)");
+    assert_ne!(no_columbus.code(), US_SETTLEMENT.code());
+
+    let before_sandy = US_NYSE.as_of(Date::ymd(2012, 10, 26));
+    assert_eq!(before_sandy.name(), "US NYSE as of 2012-10-26");
+    assert_eq!(before_sandy.code(), "US NYSE as of 2012-10-26");
+    assert_eq!(
+        no_columbus.as_of(Date::ymd(2020, 1, 1)).name(),
+        "US Settlement (modified) as of 2020-01-01"
+    );
+
+    let metals = UnitedKingdom::new(UKMarket::Metals).as_of(Date::ymd(2020, 1, 1));
+    assert_eq!(metals.name(), "UK Metals as of 2020-01-01");
+
+    // A name set by the caller is kept as is.
+    let named = US_NYSE
+        .as_of(Date::ymd(2001, 9, 10))
+        .named(format!("{} before 9/11", US_NYSE.name))
+        .without("Good Friday");
+    assert_eq!(named.name(), "US NYSE before 9/11");
+    assert_eq!(
+        NO_COLUMBUS_OR_VETERANS.name(),
+        "US Settlement (no Columbus/Veterans Day)"
+    );
+}
@@ -0,0 +1,85 @@
+use business_calendar::diff::{diff, Side};
+use business_calendar::markets::uk::{UKMarket, UnitedKingdom};
+use business_calendar::markets::us::{USMarket, UnitedStates};
+use business_calendar::{Date, DateRange};
+
+#[test]
+fn identical_calendars_have_no_differences() {
+    let sofr = UnitedStates::new(USMarket::SOFR);
+    let gov = UnitedStates::new(USMarket::GovernmentBond);
+    let range = DateRange::inclusive(Date::ymd(2000, 1, 1), Date::ymd(2030, 12, 31));
+    assert!(diff(&sofr, &gov, range).is_empty());
+}
+
+#[test]
+fn sofr_versus_nyse() {
+    let sofr = UnitedStates::new(USMarket::SOFR);
+    let nyse = UnitedStates::new(USMarket::NYSE);
+    let range = DateRange::year_of(Date::ymd(2024, 1, 1));
+
+    let diffs = diff(&sofr, &nyse, range);
+    let dates: Vec<Date> = diffs.iter().map(|d| d.date).collect();
+    // Columbus Day and Veterans Day close SOFR but not NYSE.
+    assert_eq!(
+        dates,
+        vec![Date::ymd(2024, 10, 14), Date::ymd(2024, 11, 11)]
+    );
+    assert!(diffs.iter().all(|d| d.business_day_in == Side::Right));
+    assert_eq!(diffs[0].rules[0].name, "Columbus Day");
+    assert_eq!(diffs[1].rules[0].name, "Veterans Day");
+}
+
+#[test]
+fn settlement_versus_libor_impact() {
+    let settlement = UnitedStates::new(USMarket::Settlement);
+    let libor = UnitedStates::new(USMarket::LiborImpact);
+    let range = DateRange::inclusive(Date::ymd(2010, 1, 1), Date::ymd(2025, 12, 31));
+
+    let diffs = diff(&settlement, &libor, range);
+    let dates: Vec<Date> = diffs.iter().map(|d| d.date).collect();
+    // Independence Day on a weekend since 2015: 2015 (Sat), 2020 (Sat), 2021 (Sun).
+    assert_eq!(
+        dates,
+        vec![
+            Date::ymd(2015, 7, 3),
+            Date::ymd(2020, 7, 3),
+            Date::ymd(2021, 7, 5)
+        ]
+    );
+    for d in &diffs {
+        assert_eq!(d.business_day_in, Side::Right);
+        assert_eq!(d.rules.len(), 1);
+        assert_eq!(d.rules[0].name, "Independence Day");
+    }
+}
+
+#[test]
+fn uk_settlement_and_exchange_agree() {
+    let settlement = UnitedKingdom::new(UKMarket::Settlement);
+    let exchange = UnitedKingdom::new(UKMarket::Exchange);
+    let range = DateRange::inclusive(Date::ymd(1982, 1, 1), Date::ymd(2030, 12, 31));
+    assert!(diff(&settlement, &exchange, range).is_empty());
+}
+
+#[test]
+fn uk_exchange_versus_an_earlier_view_reports_special_days() {
+    let exchange = UnitedKingdom::new(UKMarket::Exchange);
+    let before = exchange.as_of(Date::ymd(2020, 1, 1));
+    let range = DateRange::year_of(Date::ymd(2022, 1, 1));
+
+    let diffs = diff(&before, &exchange, range);
+    let funeral = diffs
+        .iter()
+        .find(|d| d.date == Date::ymd(2022, 9, 19))
+        .expect("state funeral differs");
+    assert_eq!(funeral.business_day_in, Side::Left);
+    assert_eq!(funeral.rules[0].name, "State Funeral of Queen Elizabeth II");
+
+    // The 2022 Spring Bank Holiday moved from 30 May to 2 June.
+    let moved = diffs
+        .iter()
+        .find(|d| d.date == Date::ymd(2022, 5, 30))
+        .expect("moved bank holiday differs");
+    assert_eq!(moved.business_day_in, Side::Right);
+    assert_eq!(moved.rules[0].name, "Spring Bank Holiday");
+}
@@ -0,0 +1,40 @@
+use business_calendar::markets::us::{USMarket, UnitedStates};
+use business_calendar::{Calendar, Date, HolidayRule, NamedRule, YearRange};
+
+#[test]
+fn juneteenth_2021_observed_by_settlement_and_fed() {
+    let observed = Date::ymd(2021, 6, 18);
+    for market in [USMarket::Settlement, USMarket::FederalReserve] {
+        let cal = UnitedStates::new(market);
+        assert!(!cal.is_business_day(observed), "{market:?}");
+        let e = cal.explain(observed);
+        assert_eq!(e.holidays[0].name, "Juneteenth National Independence Day");
+        assert_eq!(e.holidays[0].actual, Date::ymd(2021, 6, 19));
+    }
+    // Not a holiday before it was signed into law.
+    let settlement = UnitedStates::new(USMarket::Settlement);
+    assert!(settlement.is_business_day(Date::ymd(2020, 6, 19)));
+    // NYSE only closed from 2022.
+    assert!(UnitedStates::new(USMarket::NYSE).is_business_day(observed));
+}
+
+#[test]
+fn effective_window_cuts_rules_mid_year() {
+    let rule = NamedRule::new(
+        "Mid-year holiday",
+        HolidayRule::Fixed {
+            month: 6,
+            day: 1,
+            years: YearRange::always(),
+        },
+    )
+    .effective_from(Date::ymd(2020, 3, 1))
+    .effective_to(Date::ymd(2023, 5, 31));
+
+    assert!(!rule.is_holiday(Date::ymd(2019, 6, 1), 2019));
+    assert!(rule.is_holiday(Date::ymd(2020, 6, 1), 2020));
+    assert!(rule.is_holiday(Date::ymd(2022, 6, 1), 2022));
+    assert!(!rule.is_holiday(Date::ymd(2023, 6, 1), 2023));
+    assert!(rule.occurrence(2021).is_some());
+    assert!(rule.occurrence(2023).is_none());
+}
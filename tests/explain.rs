@@ -0,0 +1,78 @@
+use business_calendar::markets::fr::{FRMarket, France};
+use business_calendar::markets::uk::{UKMarket, UnitedKingdom, UK_EXCHANGE};
+use business_calendar::markets::us::{USMarket, UnitedStates};
+use business_calendar::{Calendar, Date};
+
+#[test]
+fn explain_observed_holiday() {
+    let cal = UnitedStates::new(USMarket::Settlement);
+    // 4 July 2020 was a Saturday, observed on Friday 3 July.
+    let e = cal.explain(Date::ymd(2020, 7, 3));
+    assert!(!e.business_day);
+    assert!(!e.weekend);
+    assert_eq!(e.holidays.len(), 1);
+    assert_eq!(e.holidays[0].name, "Independence Day");
+    assert_eq!(e.holidays[0].actual, Date::ymd(2020, 7, 4));
+    assert_eq!(e.holidays[0].observed, Date::ymd(2020, 7, 3));
+    assert!(e.overrides.is_empty());
+}
+
+#[test]
+fn weekend_observance_stays_in_the_month() {
+    let cal = UnitedStates::new(USMarket::Settlement);
+    // 1 January 2022 was a Saturday; moving it to Friday would leave January.
+    let e = cal.explain(Date::ymd(2022, 1, 1));
+    assert!(e.weekend);
+    assert_eq!(e.holidays.len(), 1);
+    assert_eq!(e.holidays[0].actual, Date::ymd(2022, 1, 1));
+    assert_eq!(e.holidays[0].observed, Date::ymd(2022, 1, 1));
+    assert!(cal.is_business_day(Date::ymd(2021, 12, 31)));
+    assert!(cal.explain(Date::ymd(2021, 12, 31)).holidays.is_empty());
+}
+
+#[test]
+fn explain_weekend_and_business_day() {
+    let cal = UnitedStates::new(USMarket::NYSE);
+    let saturday = cal.explain(Date::ymd(2025, 3, 8));
+    assert!(saturday.weekend);
+    assert!(!saturday.business_day);
+    assert!(saturday.holidays.is_empty());
+
+    let tuesday = cal.explain(Date::ymd(2025, 3, 11));
+    assert!(tuesday.business_day);
+    assert!(!tuesday.weekend);
+    assert!(tuesday.holidays.is_empty());
+}
+
+#[test]
+fn explain_libor_impact_override() {
+    let cal = UnitedStates::new(USMarket::LiborImpact);
+    let e = cal.explain(Date::ymd(2015, 7, 3));
+    assert!(e.business_day);
+    assert_eq!(e.holidays[0].name, "Independence Day");
+    assert_eq!(e.overrides.len(), 1);
+    assert!(e.overrides[0].business_day);
+
+    // Before 2015 the settlement rule applies unchanged.
+    let e = cal.explain(Date::ymd(2009, 7, 3));
+    assert!(!e.business_day);
+    assert!(e.overrides.is_empty());
+}
+
+#[test]
+fn explain_uk_and_france() {
+    let uk = UnitedKingdom::new(UKMarket::Exchange);
+    let e = uk.explain(Date::ymd(2022, 6, 3));
+    assert_eq!(e.holidays[0].name, "Platinum Jubilee Bank Holiday");
+    assert_eq!(UK_EXCHANGE.explain(Date::ymd(2022, 6, 3)), e);
+
+    // Boxing Day 2021 fell on a Sunday and was observed on Monday 27 December.
+    let e = UnitedKingdom::new(UKMarket::Settlement).explain(Date::ymd(2021, 12, 27));
+    assert_eq!(e.holidays[0].name, "Boxing Day");
+    assert_eq!(e.holidays[0].actual, Date::ymd(2021, 12, 26));
+
+    let fr = France::new(FRMarket::Settlement);
+    let e = fr.explain(Date::ymd(2025, 7, 14));
+    assert_eq!(e.holidays[0].name, "Bastille Day");
+    assert_eq!(e.holidays[0].observed, Date::ymd(2025, 7, 14));
+}
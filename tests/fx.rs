@@ -0,0 +1,122 @@
+use business_calendar::fx::{Currency, CurrencyPair, FxTenor};
+use business_calendar::{Calendar, Date, Period};
+
+#[test]
+fn spot_lags() {
+    assert_eq!(
+        CurrencyPair::new(Currency::EUR, Currency::USD).spot_lag(),
+        2
+    );
+    assert_eq!(
+        CurrencyPair::new(Currency::USD, Currency::CAD).spot_lag(),
+        1
+    );
+    assert_eq!(
+        CurrencyPair::new(Currency::TRY, Currency::USD).spot_lag(),
+        1
+    );
+    assert_eq!(
+        CurrencyPair::new(Currency::EUR, Currency::CAD).spot_lag(),
+        2
+    );
+}
+
+#[test]
+fn usd_holiday_on_spot_rolls_value_date() {
+    let eur_usd = CurrencyPair::new(Currency::EUR, Currency::USD);
+    // Wed 2 Jul 2025: T+2 is Independence Day.
+    assert_eq!(
+        eur_usd.spot_date(Date::ymd(2025, 7, 2)),
+        Date::ymd(2025, 7, 7)
+    );
+    assert!(!eur_usd.is_business_day(Date::ymd(2025, 7, 4)));
+}
+
+#[test]
+fn usd_holiday_on_t_plus_1_does_not_block_spot() {
+    let eur_gbp = CurrencyPair::new(Currency::EUR, Currency::GBP);
+    // Thu 3 Jul 2025: T+1 is Independence Day, which only matters for the value date.
+    assert_eq!(
+        eur_gbp.spot_date(Date::ymd(2025, 7, 3)),
+        Date::ymd(2025, 7, 7)
+    );
+}
+
+#[test]
+fn non_usd_holiday_on_t_plus_1_blocks_spot() {
+    let gbp_usd = CurrencyPair::new(Currency::GBP, Currency::USD);
+    // Fri 2 May 2025: T+1 is the UK Early May bank holiday (Mon 5 May).
+    assert_eq!(
+        gbp_usd.spot_date(Date::ymd(2025, 5, 2)),
+        Date::ymd(2025, 5, 7)
+    );
+}
+
+#[test]
+fn t_plus_1_pairs() {
+    let usd_cad = CurrencyPair::new(Currency::USD, Currency::CAD);
+    assert_eq!(
+        usd_cad.spot_date(Date::ymd(2025, 7, 1)),
+        Date::ymd(2025, 7, 2)
+    );
+    // T+1 is Independence Day: spot rolls to Monday.
+    assert_eq!(
+        usd_cad.spot_date(Date::ymd(2025, 7, 3)),
+        Date::ymd(2025, 7, 7)
+    );
+}
+
+#[test]
+fn forward_value_dates() {
+    let eur_usd = CurrencyPair::new(Currency::EUR, Currency::USD);
+
+    // Trade Wed 29 Jan 2025, spot Fri 31 Jan (last good day of January):
+    // end-of-month forwards stay on month end.
+    let trade = Date::ymd(2025, 1, 29);
+    assert_eq!(eur_usd.spot_date(trade), Date::ymd(2025, 1, 31));
+    assert_eq!(
+        eur_usd.forward_date(trade, Period::months(1)),
+        Date::ymd(2025, 2, 28)
+    );
+    assert_eq!(
+        eur_usd.forward_date(trade, Period::months(3)),
+        Date::ymd(2025, 4, 30)
+    );
+
+    // Week tenors are calendar days from spot, rolled Following.
+    assert_eq!(
+        eur_usd.forward_date(trade, Period::weeks(1)),
+        Date::ymd(2025, 2, 7)
+    );
+
+    // Mid-month spot: plain month arithmetic.
+    let trade = Date::ymd(2025, 1, 13);
+    assert_eq!(
+        eur_usd.forward_date(trade, Period::months(1)),
+        Date::ymd(2025, 2, 18)
+    );
+}
+
+#[test]
+fn eur_settles_on_target_days() {
+    let eur_usd = CurrencyPair::new(Currency::EUR, Currency::USD);
+    // Tue 29 Apr 2025: T+2 is Labour Day, a TARGET holiday only.
+    assert_eq!(
+        eur_usd.spot_date(Date::ymd(2025, 4, 29)),
+        Date::ymd(2025, 5, 2)
+    );
+    assert!(!Currency::EUR.is_business_day(Date::ymd(2025, 12, 26)));
+}
+
+#[test]
+fn money_market_tenors_are_not_measured_from_spot() {
+    let eur_usd = CurrencyPair::new(Currency::EUR, Currency::USD);
+    // Mon 13 Jan 2025: spot is Wed 15 Jan.
+    let trade = Date::ymd(2025, 1, 13);
+    let at = |tenor: &str| eur_usd.forward_date(trade, tenor.parse::<FxTenor>().unwrap());
+    assert_eq!(at("ON"), Date::ymd(2025, 1, 14));
+    assert_eq!(at("T/N"), Date::ymd(2025, 1, 15));
+    assert_eq!(at("SN"), Date::ymd(2025, 1, 16));
+    assert_eq!(at("1W"), Date::ymd(2025, 1, 22));
+    assert!("1X".parse::<FxTenor>().is_err());
+}
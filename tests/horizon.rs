@@ -0,0 +1,113 @@
+use business_calendar::markets::uk::{UK_EXCHANGE, UK_SETTLEMENT};
+use business_calendar::markets::us::nyse::US_NYSE;
+use business_calendar::markets::us::settlement::US_SETTLEMENT;
+use business_calendar::markets::us::{USMarket, UnitedStates};
+use business_calendar::{
+    Answer, AnyCalendar, BusinessDayConvention, Calendar, Date, OutOfRangePolicy, Period, YearRange,
+};
+
+#[test]
+fn dates_within_supported_years_are_known() {
+    let good_friday = Date::ymd(2025, 4, 18);
+    assert_eq!(
+        US_NYSE.try_is_business_day(good_friday),
+        Ok(Answer::Known(false))
+    );
+    assert_eq!(US_NYSE.try_is_holiday(good_friday), Ok(Answer::Known(true)));
+    assert_eq!(
+        US_NYSE.try_adjust(good_friday, BusinessDayConvention::Following),
+        Ok(Answer::Known(Date::ymd(2025, 4, 21)))
+    );
+}
+
+#[test]
+fn error_policy_is_the_default() {
+    let date = Date::ymd(2031, 1, 2);
+    let err = UK_SETTLEMENT.try_is_business_day(date).unwrap_err();
+    assert_eq!(err.date(), date);
+    assert_eq!(err.supported_years(), YearRange::between(1982, 2030));
+    assert_eq!(
+        err.to_string(),
+        "2031-01-02 is outside the supported years 1982..=2030"
+    );
+
+    // Wrappers and pointers report the same horizon.
+    let nyse = AnyCalendar::from(UnitedStates::new(USMarket::NYSE));
+    assert!(nyse.try_is_holiday(date).is_err());
+    assert!((&nyse as &dyn Calendar).try_is_holiday(date).is_err());
+
+    // Rolling across the end of the horizon is an error too.
+    let eve = Date::ymd(2030, 12, 31);
+    assert!(UK_SETTLEMENT
+        .try_advance_by(
+            eve,
+            Period::days(1),
+            BusinessDayConvention::Following,
+            false
+        )
+        .is_err());
+}
+
+#[test]
+fn project_policy_flags_answers() {
+    let cal = US_SETTLEMENT.with_horizon(YearRange::until(2030), OutOfRangePolicy::Project);
+    assert_eq!(cal.supported_years(), YearRange::between(1971, 2030));
+    assert_eq!(
+        cal.try_is_business_day(Date::ymd(2030, 7, 4)),
+        Ok(Answer::Known(false))
+    );
+    let projected = cal.try_is_business_day(Date::ymd(2031, 7, 4)).unwrap();
+    assert!(projected.is_projected());
+    assert!(!projected.value());
+    assert_eq!(
+        cal.try_adjust(Date::ymd(2030, 12, 31), BusinessDayConvention::Following),
+        Ok(Answer::Known(Date::ymd(2030, 12, 31)))
+    );
+    assert_eq!(
+        cal.try_advance_by(
+            Date::ymd(2030, 12, 31),
+            Period::days(1),
+            BusinessDayConvention::Following,
+            false
+        ),
+        Ok(Answer::Projected(Date::ymd(2031, 1, 2)))
+    );
+}
+
+#[test]
+#[should_panic(expected = "2031-07-04 is outside the supported years")]
+fn panic_policy_panics_on_any_query() {
+    let cal = US_SETTLEMENT.with_horizon(YearRange::until(2030), OutOfRangePolicy::Panic);
+    assert!(cal.is_business_day(Date::ymd(2030, 7, 5)));
+    cal.is_business_day(Date::ymd(2031, 7, 4));
+}
+
+#[test]
+fn shipped_calendars_declare_their_horizon() {
+    assert_eq!(
+        US_SETTLEMENT.supported_years(),
+        YearRange::between(1971, 2030)
+    );
+    assert_eq!(US_NYSE.supported_years(), YearRange::between(1995, 2030));
+    // UK settlement records the same special bank holidays as the exchange.
+    assert_eq!(
+        UK_SETTLEMENT.supported_years(),
+        UK_EXCHANGE.supported_years()
+    );
+    assert!(!UK_SETTLEMENT.is_business_day(Date::ymd(1999, 12, 31)));
+
+    let far = Date::ymd(2500, 1, 3);
+    let err = US_SETTLEMENT.try_is_business_day(far).unwrap_err();
+    assert_eq!(err.supported_years(), YearRange::between(1971, 2030));
+    assert!(US_NYSE.try_is_business_day(Date::ymd(2150, 1, 5)).is_err());
+    // Before the horizon too: closures such as Nixon's funeral are missing.
+    assert!(US_NYSE.try_is_holiday(Date::ymd(1994, 4, 27)).is_err());
+
+    let nyse = UnitedStates::new(USMarket::NYSE);
+    assert_eq!(nyse.supported_years(), YearRange::between(1995, 2030));
+    let projected = nyse
+        .with_horizon(YearRange::always(), OutOfRangePolicy::Project)
+        .try_is_business_day(Date::ymd(2150, 1, 5))
+        .unwrap();
+    assert_eq!(projected, Answer::Projected(true));
+}
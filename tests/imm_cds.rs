@@ -0,0 +1,121 @@
+use business_calendar::cds::{
+    cds_maturity, cds_roll_date, is_cds_date, next_cds_date, next_cds_date_adjusted,
+    previous_cds_date,
+};
+use business_calendar::imm::{
+    imm_code, imm_date_from_code, is_imm_date, next_imm_date, next_imm_date_adjusted,
+};
+use business_calendar::markets::us::{USMarket, UnitedStates};
+use business_calendar::Date;
+
+#[test]
+fn imm_dates_are_third_wednesdays() {
+    let h6 = Date::ymd(2026, 3, 18);
+    assert!(is_imm_date(h6, true));
+    assert!(!is_imm_date(Date::ymd(2026, 3, 11), false));
+    // April IMM date is off the main cycle.
+    let j6 = Date::ymd(2026, 4, 15);
+    assert!(is_imm_date(j6, false));
+    assert!(!is_imm_date(j6, true));
+
+    assert_eq!(next_imm_date(Date::ymd(2026, 3, 17), true), h6);
+    assert_eq!(next_imm_date(h6, true), Date::ymd(2026, 6, 17));
+    assert_eq!(next_imm_date(h6, false), j6);
+    assert_eq!(next_imm_date(Date::ymd(2025, 12, 31), true), h6);
+}
+
+#[test]
+fn imm_codes_round_trip() {
+    let h6 = Date::ymd(2026, 3, 18);
+    assert_eq!(imm_code(h6).as_deref(), Some("H6"));
+    assert_eq!(imm_code(Date::ymd(2026, 3, 19)), None);
+
+    assert_eq!(imm_date_from_code("H6", Date::ymd(2026, 1, 1)), Some(h6));
+    assert_eq!(imm_date_from_code("H6", h6), Some(h6));
+    // Once this decade's H6 has passed, the code refers to the next decade.
+    let next = imm_date_from_code("H6", Date::ymd(2026, 3, 19)).unwrap();
+    assert_eq!(next.year(), 2036);
+    assert!(is_imm_date(next, true));
+
+    assert_eq!(
+        imm_date_from_code("Z5", Date::ymd(2025, 1, 1)),
+        Some(Date::ymd(2025, 12, 17))
+    );
+    assert_eq!(imm_date_from_code("A6", Date::ymd(2026, 1, 1)), None);
+    assert_eq!(imm_date_from_code("H", Date::ymd(2026, 1, 1)), None);
+}
+
+#[test]
+fn imm_adjusted_rolls_over_holidays() {
+    let cal = UnitedStates::new(USMarket::Settlement);
+    // Juneteenth 2024 fell on the June IMM date.
+    let d = next_imm_date_adjusted(Date::ymd(2024, 6, 1), true, &cal);
+    assert_eq!(d, Date::ymd(2024, 6, 20));
+}
+
+#[test]
+fn cds_quarterly_dates() {
+    assert!(is_cds_date(Date::ymd(2024, 6, 20)));
+    assert!(!is_cds_date(Date::ymd(2024, 7, 20)));
+
+    assert_eq!(
+        next_cds_date(Date::ymd(2024, 6, 19)),
+        Date::ymd(2024, 6, 20)
+    );
+    assert_eq!(
+        next_cds_date(Date::ymd(2024, 6, 20)),
+        Date::ymd(2024, 9, 20)
+    );
+    assert_eq!(
+        next_cds_date(Date::ymd(2024, 12, 21)),
+        Date::ymd(2025, 3, 20)
+    );
+    assert_eq!(
+        previous_cds_date(Date::ymd(2024, 6, 20)),
+        Date::ymd(2024, 3, 20)
+    );
+    assert_eq!(
+        previous_cds_date(Date::ymd(2024, 1, 5)),
+        Date::ymd(2023, 12, 20)
+    );
+
+    // 20 June 2021 was a Sunday.
+    let cal = UnitedStates::new(USMarket::Settlement);
+    assert_eq!(
+        next_cds_date_adjusted(Date::ymd(2021, 6, 1), &cal),
+        Date::ymd(2021, 6, 21)
+    );
+}
+
+#[test]
+fn cds_2015_semi_annual_roll() {
+    assert_eq!(
+        cds_roll_date(Date::ymd(2016, 3, 19)),
+        Date::ymd(2015, 9, 20)
+    );
+    assert_eq!(
+        cds_roll_date(Date::ymd(2016, 3, 20)),
+        Date::ymd(2016, 3, 20)
+    );
+
+    assert_eq!(
+        cds_maturity(Date::ymd(2016, 3, 19), 60),
+        Date::ymd(2020, 12, 20)
+    );
+    assert_eq!(
+        cds_maturity(Date::ymd(2016, 3, 20), 60),
+        Date::ymd(2021, 6, 20)
+    );
+    assert_eq!(
+        cds_maturity(Date::ymd(2016, 5, 1), 60),
+        Date::ymd(2021, 6, 20)
+    );
+    assert_eq!(
+        cds_maturity(Date::ymd(2016, 10, 1), 60),
+        Date::ymd(2021, 12, 20)
+    );
+    assert_eq!(
+        cds_maturity(Date::ymd(2017, 2, 1), 60),
+        Date::ymd(2021, 12, 20)
+    );
+}
@@ -0,0 +1,90 @@
+use business_calendar::fx::{Currency, CurrencyPair};
+use business_calendar::markets::eu::{EUMarket, Europe};
+use business_calendar::markets::fr::{FRMarket, France};
+use business_calendar::markets::uk::{UKMarket, UnitedKingdom, UK_METALS};
+use business_calendar::markets::us::settlement::US_SETTLEMENT;
+use business_calendar::markets::us::{USMarket, UnitedStates};
+use business_calendar::{Calendar, HolidayCategories, YearRange, UNNAMED_CALENDAR};
+
+#[test]
+fn market_metadata() {
+    let nyse = UnitedStates::new(USMarket::NYSE);
+    assert_eq!(nyse.name(), "US NYSE");
+    assert_eq!(nyse.code(), "US-NYSE");
+    assert_eq!(nyse.country_code(), Some("US"));
+    assert_eq!(nyse.mic(), Some("XNYS"));
+    assert_eq!(nyse.settlement_currency(), Some(Currency::USD));
+    assert_eq!(nyse.time_zone(), Some("America/New_York"));
+
+    let metals = UnitedKingdom::new(UKMarket::Metals);
+    assert_eq!(metals.name(), "UK Metals");
+    assert_eq!(metals.name(), UK_METALS.name());
+    assert_eq!(metals.country_code(), Some("GB"));
+    assert_eq!(metals.mic(), Some("XLME"));
+    assert_eq!(metals.settlement_currency(), Some(Currency::USD));
+    assert_eq!(
+        UnitedKingdom::new(UKMarket::Exchange).settlement_currency(),
+        Some(Currency::GBP)
+    );
+
+    let paris = France::new(FRMarket::Exchange);
+    assert_eq!(paris.code(), "FR-EXCHANGE");
+    assert_eq!(paris.mic(), Some("XPAR"));
+    assert_eq!(paris.time_zone(), Some("Europe/Paris"));
+    assert_eq!(paris.settlement_currency(), Some(Currency::EUR));
+    assert_eq!(France::new(FRMarket::Settlement).mic(), None);
+}
+
+#[test]
+fn codes_are_unique() {
+    let us = [
+        USMarket::Settlement,
+        USMarket::LiborImpact,
+        USMarket::NYSE,
+        USMarket::GovernmentBond,
+        USMarket::SOFR,
+        USMarket::NERC,
+        USMarket::FederalReserve,
+    ]
+    .map(UnitedStates::new);
+    let uk = [UKMarket::Settlement, UKMarket::Exchange, UKMarket::Metals].map(UnitedKingdom::new);
+    let fr = [FRMarket::Settlement, FRMarket::Exchange].map(France::new);
+    let eu = [EUMarket::Target].map(Europe::new);
+    let calendars: Vec<&dyn Calendar> = us
+        .iter()
+        .map(|c| c as &dyn Calendar)
+        .chain(uk.iter().map(|c| c as &dyn Calendar))
+        .chain(fr.iter().map(|c| c as &dyn Calendar))
+        .chain(eu.iter().map(|c| c as &dyn Calendar))
+        .collect();
+
+    let mut codes: Vec<&str> = calendars.iter().map(|c| c.code()).collect();
+    codes.sort_unstable();
+    codes.dedup();
+    assert_eq!(codes.len(), calendars.len());
+    assert!(calendars.iter().all(|c| c.name() != UNNAMED_CALENDAR));
+}
+
+#[test]
+fn supported_years_follow_the_declared_horizon() {
+    let nyse = UnitedStates::new(USMarket::NYSE);
+    assert_eq!(nyse.supported_years(), YearRange::between(1995, 2030));
+    assert_eq!(
+        US_SETTLEMENT.supported_years(),
+        YearRange::between(1971, 2030)
+    );
+    assert_eq!(
+        nyse.with_categories(HolidayCategories::SCHEDULED).code(),
+        "US-NYSE"
+    );
+}
+
+#[test]
+fn defaults_for_unnamed_calendars() {
+    let pair = CurrencyPair::new(Currency::EUR, Currency::USD);
+    assert_eq!(pair.name(), UNNAMED_CALENDAR);
+    assert_eq!(pair.code(), UNNAMED_CALENDAR);
+    assert_eq!(pair.mic(), None);
+    assert_eq!(pair.settlement_currency(), None);
+    assert_eq!(pair.supported_years(), YearRange::always());
+}
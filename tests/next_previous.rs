@@ -0,0 +1,76 @@
+use business_calendar::fx::{Currency, CurrencyPair};
+use business_calendar::markets::uk::{UKMarket, UnitedKingdom};
+use business_calendar::markets::us::{USMarket, UnitedStates};
+use business_calendar::{Calendar, Date, UNNAMED_HOLIDAY};
+
+#[test]
+fn next_and_previous_business_day_are_strict() {
+    let nyse = UnitedStates::new(USMarket::NYSE);
+    // Friday before Thanksgiving weekend; Thursday is the holiday.
+    assert_eq!(
+        nyse.next_business_day(Date::ymd(2026, 11, 25)),
+        Date::ymd(2026, 11, 27)
+    );
+    assert_eq!(
+        nyse.previous_business_day(Date::ymd(2026, 11, 27)),
+        Date::ymd(2026, 11, 25)
+    );
+    // A business day is never returned for itself.
+    assert_eq!(
+        nyse.next_business_day(Date::ymd(2026, 11, 2)),
+        Date::ymd(2026, 11, 3)
+    );
+}
+
+#[test]
+fn next_and_previous_holiday_are_named() {
+    let nyse = UnitedStates::new(USMarket::NYSE);
+    let next = nyse.next_holiday(Date::ymd(2026, 11, 1)).unwrap();
+    assert_eq!(next.name, "Thanksgiving Day");
+    assert_eq!(next.observed, Date::ymd(2026, 11, 26));
+
+    let previous = nyse.previous_holiday(Date::ymd(2025, 1, 1)).unwrap();
+    assert_eq!(previous.name, "Christmas Day");
+    assert_eq!(previous.observed, Date::ymd(2024, 12, 25));
+
+    // Strictly after: asking from the holiday itself moves on.
+    let after = nyse.next_holiday(Date::ymd(2026, 11, 26)).unwrap();
+    assert_eq!(after.name, "Christmas Day");
+}
+
+#[test]
+fn observed_dates_and_market_overrides() {
+    let settlement = UnitedStates::new(USMarket::Settlement);
+    let occ = settlement.next_holiday(Date::ymd(2015, 7, 1)).unwrap();
+    assert_eq!(occ.name, "Independence Day");
+    assert_eq!(occ.actual, Date::ymd(2015, 7, 4));
+    assert_eq!(occ.observed, Date::ymd(2015, 7, 3));
+
+    // LiborImpact keeps the observed Independence Day open from 2015.
+    let libor = UnitedStates::new(USMarket::LiborImpact);
+    let occ = libor.next_holiday(Date::ymd(2015, 7, 1)).unwrap();
+    assert_eq!(occ.name, "Labor Day");
+    assert_eq!(occ.observed, Date::ymd(2015, 9, 7));
+    let occ = libor.previous_holiday(Date::ymd(2015, 9, 7)).unwrap();
+    assert_eq!(occ.name, "Memorial Day");
+
+    let uk = UnitedKingdom::new(UKMarket::Exchange);
+    let occ = uk.next_holiday(Date::ymd(2022, 6, 1)).unwrap();
+    assert_eq!(occ.name, "Spring Bank Holiday");
+    assert_eq!(occ.observed, Date::ymd(2022, 6, 2));
+}
+
+#[test]
+fn calendars_without_rules_report_unnamed_holidays() {
+    let pair = CurrencyPair::new(Currency::EUR, Currency::USD);
+    let occ = pair.next_holiday(Date::ymd(2025, 7, 1)).unwrap();
+    assert_eq!(occ.name, UNNAMED_HOLIDAY);
+    assert_eq!(occ.observed, Date::ymd(2025, 7, 4));
+    // Juneteenth is a USD holiday, so it closes the pair.
+    assert_eq!(
+        pair.previous_holiday(Date::ymd(2025, 7, 4))
+            .unwrap()
+            .observed,
+        Date::ymd(2025, 6, 19)
+    );
+}
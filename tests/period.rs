@@ -0,0 +1,96 @@
+use business_calendar::markets::us::{USMarket, UnitedStates};
+use business_calendar::{BusinessDayConvention, Calendar, Date, Period};
+
+#[test]
+fn parse_simple_and_compound_tenors() {
+    assert_eq!("3M".parse(), Ok(Period::months(3)));
+    assert_eq!("2w".parse(), Ok(Period::weeks(2)));
+    assert_eq!("10D".parse(), Ok(Period::days(10)));
+    assert_eq!("5Y".parse(), Ok(Period::years(5)));
+    assert_eq!("1Y6M".parse(), Ok(Period::months(18)));
+    assert_eq!("1W2D".parse(), Ok(Period::days(9)));
+    assert_eq!("-1M".parse(), Ok(Period::months(-1)));
+
+    assert_eq!("ON".parse(), Ok(Period::days(1)));
+    assert_eq!("TN".parse(), Ok(Period::days(2)));
+    assert_eq!("SN".parse(), Ok(Period::days(3)));
+
+    for bad in [
+        "",
+        "M",
+        "3",
+        "3X",
+        "1M2D",
+        "Y1",
+        "1Y2Y",
+        "1W2W",
+        "999999999Y",
+        "178956970Y8M",
+    ] {
+        assert!(bad.parse::<Period>().is_err(), "{bad:?} should not parse");
+    }
+    assert_eq!(Period::months(18).to_string(), "18M");
+}
+
+#[test]
+fn date_plus_period_clamps_to_month_end() {
+    let d = Date::ymd(2024, 1, 31);
+    assert_eq!(d + Period::months(1), Date::ymd(2024, 2, 29));
+    assert_eq!(d + Period::months(13), Date::ymd(2025, 2, 28));
+    assert_eq!(
+        Date::ymd(2024, 2, 29) + Period::years(1),
+        Date::ymd(2025, 2, 28)
+    );
+    assert_eq!(d + Period::weeks(1), Date::ymd(2024, 2, 7));
+    assert_eq!(d - Period::months(2), Date::ymd(2023, 11, 30));
+}
+
+#[test]
+fn advance_by_days_counts_business_days() {
+    let cal = UnitedStates::new(USMarket::Settlement);
+    let following = BusinessDayConvention::Following;
+
+    // Thu 3 Jul 2025 + 1 business day skips Independence Day and the weekend.
+    let d = Date::ymd(2025, 7, 3);
+    assert_eq!(
+        cal.advance_by(d, Period::days(1), following, false),
+        Date::ymd(2025, 7, 7)
+    );
+    assert_eq!(
+        cal.advance_by(Date::ymd(2025, 7, 7), Period::days(-1), following, false),
+        d
+    );
+    // Zero days only adjusts.
+    assert_eq!(
+        cal.advance_by(Date::ymd(2025, 7, 4), Period::days(0), following, false),
+        Date::ymd(2025, 7, 7)
+    );
+}
+
+#[test]
+fn advance_by_months_with_end_of_month_rule() {
+    let cal = UnitedStates::new(USMarket::Settlement);
+    let mf = BusinessDayConvention::ModifiedFollowing;
+
+    // Fri 28 Feb 2025 is the last business day of February.
+    let d = Date::ymd(2025, 2, 28);
+    assert_eq!(
+        cal.advance_by(d, Period::months(1), mf, true),
+        Date::ymd(2025, 3, 31)
+    );
+    assert_eq!(
+        cal.advance_by(d, Period::months(1), mf, false),
+        Date::ymd(2025, 3, 28)
+    );
+
+    // 31 May 2025 is a Saturday: modified following rolls back into May.
+    let d = Date::ymd(2025, 4, 30);
+    assert_eq!(
+        cal.advance_by(d, Period::months(1), mf, false),
+        Date::ymd(2025, 5, 30)
+    );
+    assert_eq!(
+        cal.advance_by(Date::ymd(2025, 1, 15), "1Y".parse().unwrap(), mf, false),
+        Date::ymd(2026, 1, 15)
+    );
+}
@@ -0,0 +1,77 @@
+use business_calendar::markets::us::{USMarket, UnitedStates};
+use business_calendar::{Calendar, Date, DateRange};
+
+#[test]
+fn start_and_end_of_month() {
+    let cal = UnitedStates::new(USMarket::Settlement);
+
+    // 31 May 2025 is a Saturday.
+    assert_eq!(
+        cal.end_of_month(Date::ymd(2025, 5, 10)),
+        Date::ymd(2025, 5, 30)
+    );
+    assert!(cal.is_end_of_month(Date::ymd(2025, 5, 30)));
+    assert!(!cal.is_end_of_month(Date::ymd(2025, 5, 29)));
+    assert!(!cal.is_end_of_month(Date::ymd(2025, 5, 31)));
+
+    // 1 Sep 2025 is Labor Day.
+    assert_eq!(
+        cal.start_of_month(Date::ymd(2025, 9, 15)),
+        Date::ymd(2025, 9, 2)
+    );
+}
+
+#[test]
+fn nth_business_day_of_month_and_week() {
+    let cal = UnitedStates::new(USMarket::NYSE);
+
+    // Third business day of January 2025 (1 Jan is a holiday).
+    let jan = DateRange::month_of(Date::ymd(2025, 1, 20));
+    assert_eq!(cal.nth_business_day_of(jan, 3), Some(Date::ymd(2025, 1, 6)));
+    assert_eq!(
+        cal.nth_business_day_of(jan, -1),
+        Some(Date::ymd(2025, 1, 31))
+    );
+    assert_eq!(cal.nth_business_day_of(jan, 0), None);
+    assert_eq!(cal.nth_business_day_of(jan, 30), None);
+
+    // Last business day of the week containing Independence Day 2025.
+    let week = DateRange::week_of(Date::ymd(2025, 7, 2));
+    assert_eq!(
+        week,
+        DateRange::inclusive(Date::ymd(2025, 6, 30), Date::ymd(2025, 7, 6))
+    );
+    assert_eq!(
+        cal.nth_business_day_of(week, -1),
+        Some(Date::ymd(2025, 7, 3))
+    );
+}
+
+#[test]
+fn nth_business_day_of_quarter_and_year() {
+    let cal = UnitedStates::new(USMarket::NYSE);
+
+    let q4 = DateRange::quarter_of(Date::ymd(2024, 11, 5));
+    assert_eq!(
+        q4,
+        DateRange::inclusive(Date::ymd(2024, 10, 1), Date::ymd(2024, 12, 31))
+    );
+    assert_eq!(
+        cal.nth_business_day_of(q4, -1),
+        Some(Date::ymd(2024, 12, 31))
+    );
+    assert_eq!(
+        cal.nth_business_day_of(q4, -2),
+        Some(Date::ymd(2024, 12, 30))
+    );
+
+    let year = DateRange::year_of(Date::ymd(2025, 6, 1));
+    assert_eq!(
+        cal.nth_business_day_of(year, 1),
+        Some(Date::ymd(2025, 1, 2))
+    );
+    assert_eq!(
+        cal.nth_business_day_of(year, -1),
+        Some(Date::ymd(2025, 12, 31))
+    );
+}
@@ -0,0 +1,261 @@
+use business_calendar::{
+    Calendar, Date, DateRange, HolidayRule, NamedRule, RelativeCondition, RuleRef, StaticCalendar,
+    YearRange,
+};
+use chrono::Weekday;
+use std::time::{Duration, Instant};
+
+const fn relative(
+    name: &'static str,
+    base: RuleRef,
+    offset: i32,
+    condition: RelativeCondition,
+) -> NamedRule {
+    NamedRule::new(
+        name,
+        HolidayRule::Relative {
+            base,
+            offset,
+            condition,
+            years: YearRange::always(),
+        },
+    )
+}
+
+static RULES: &[NamedRule] = &[
+    NamedRule::new(
+        "Thanksgiving Day",
+        HolidayRule::NthWeekday {
+            month: 11,
+            weekday: Weekday::Thu,
+            nth: 4,
+            years: YearRange::always(),
+        },
+    ),
+    relative(
+        "Day after Thanksgiving",
+        RuleRef::Name("Thanksgiving Day"),
+        1,
+        RelativeCondition::Always,
+    ),
+    // Bridge Monday before a Tuesday Christmas.
+    NamedRule::new(
+        "Christmas Day",
+        HolidayRule::Fixed {
+            month: 12,
+            day: 25,
+            years: YearRange::always(),
+        },
+    ),
+    relative(
+        "Christmas Bridge",
+        RuleRef::Index(2),
+        -1,
+        RelativeCondition::BaseOn(Weekday::Tue),
+    ),
+    // A rule referencing itself must not recurse forever.
+    relative(
+        "Cycle",
+        RuleRef::Name("Cycle"),
+        1,
+        RelativeCondition::Always,
+    ),
+];
+
+static CAL: StaticCalendar = StaticCalendar {
+    name: "Relative",
+    weekend: [Weekday::Sat, Weekday::Sun],
+    rules: RULES,
+    horizon: YearRange::always(),
+};
+
+static JP_RULES: &[NamedRule] = &[
+    NamedRule::new(
+        "Respect for the Aged Day",
+        HolidayRule::NthWeekday {
+            month: 9,
+            weekday: Weekday::Mon,
+            nth: 3,
+            years: YearRange::always(),
+        },
+    ),
+    NamedRule::new(
+        "Autumnal Equinox Day",
+        HolidayRule::OneOff {
+            year: 2015,
+            month: 9,
+            day: 23,
+        },
+    ),
+    relative(
+        "Citizens' Holiday",
+        RuleRef::Name("Respect for the Aged Day"),
+        1,
+        RelativeCondition::Sandwiched,
+    ),
+    NamedRule::new(
+        "National Day",
+        HolidayRule::Fixed {
+            month: 10,
+            day: 1,
+            years: YearRange::always(),
+        },
+    ),
+    relative(
+        "Golden Week",
+        RuleRef::Name("National Day"),
+        1,
+        RelativeCondition::Weekday,
+    ),
+    relative(
+        "Golden Week",
+        RuleRef::Name("National Day"),
+        2,
+        RelativeCondition::Weekday,
+    ),
+    relative(
+        "Golden Week",
+        RuleRef::Name("National Day"),
+        3,
+        RelativeCondition::Weekday,
+    ),
+];
+
+static JP: StaticCalendar = StaticCalendar {
+    name: "Relative JP",
+    weekend: [Weekday::Sat, Weekday::Sun],
+    rules: JP_RULES,
+    horizon: YearRange::always(),
+};
+
+#[test]
+fn day_after_thanksgiving() {
+    assert!(!CAL.is_business_day(Date::ymd(2025, 11, 28)));
+    let e = CAL.explain(Date::ymd(2025, 11, 28));
+    assert_eq!(e.holidays[0].name, "Day after Thanksgiving");
+    let next = CAL.next_holiday(Date::ymd(2025, 11, 27)).unwrap();
+    assert_eq!(next.observed, Date::ymd(2025, 11, 28));
+}
+
+#[test]
+fn bridge_day_only_when_base_on_given_weekday() {
+    // Christmas 2018 was a Tuesday; 2019 a Wednesday.
+    assert!(!CAL.is_business_day(Date::ymd(2018, 12, 24)));
+    assert!(CAL.is_business_day(Date::ymd(2019, 12, 24)));
+}
+
+#[test]
+fn reference_cycles_never_match() {
+    let cycle = &RULES[4];
+    assert!(CAL.occurrence(cycle, 2025).is_none());
+    assert!(CAL.is_business_day(Date::ymd(2025, 3, 4)));
+}
+
+#[test]
+fn sandwiched_and_multi_day_holidays() {
+    // 2015: Mon 21 Sep and Wed 23 Sep were holidays, so Tue 22 Sep was too.
+    assert!(!JP.is_business_day(Date::ymd(2015, 9, 22)));
+    assert!(JP.is_business_day(Date::ymd(2016, 9, 20)));
+
+    // National Day 2025 is a Wednesday: Thursday and Friday follow, Saturday is skipped.
+    let days: Vec<_> = JP
+        .holidays(DateRange::inclusive(
+            Date::ymd(2025, 10, 1),
+            Date::ymd(2025, 10, 7),
+        ))
+        .collect();
+    assert_eq!(
+        days,
+        [
+            Date::ymd(2025, 10, 1),
+            Date::ymd(2025, 10, 2),
+            Date::ymd(2025, 10, 3)
+        ]
+    );
+    assert!(JP.occurrence(&JP_RULES[6], 2025).is_none());
+}
+
+static SANDWICH_RULES: &[NamedRule] = &[
+    NamedRule::new(
+        "Constitution Day",
+        HolidayRule::Fixed {
+            month: 5,
+            day: 2,
+            years: YearRange::always(),
+        },
+    ),
+    NamedRule::new(
+        "Children's Day",
+        HolidayRule::Fixed {
+            month: 5,
+            day: 4,
+            years: YearRange::always(),
+        },
+    ),
+    relative(
+        "Bridge 1",
+        RuleRef::Index(0),
+        -2,
+        RelativeCondition::Sandwiched,
+    ),
+    relative(
+        "Bridge 2",
+        RuleRef::Index(0),
+        -1,
+        RelativeCondition::Sandwiched,
+    ),
+    relative(
+        "Bridge 3",
+        RuleRef::Index(0),
+        1,
+        RelativeCondition::Sandwiched,
+    ),
+    relative(
+        "Bridge 4",
+        RuleRef::Index(0),
+        3,
+        RelativeCondition::Sandwiched,
+    ),
+    relative(
+        "Bridge 5",
+        RuleRef::Index(0),
+        4,
+        RelativeCondition::Sandwiched,
+    ),
+    relative(
+        "Bridge 6",
+        RuleRef::Index(0),
+        5,
+        RelativeCondition::Sandwiched,
+    ),
+];
+
+static SANDWICH: StaticCalendar = StaticCalendar {
+    name: "Sandwiched",
+    weekend: [Weekday::Sat, Weekday::Sun],
+    rules: SANDWICH_RULES,
+    horizon: YearRange::always(),
+};
+
+#[test]
+fn sandwiched_rules_resolve_in_bounded_time() {
+    let start = Instant::now();
+    let year = DateRange::inclusive(Date::ymd(2023, 1, 1), Date::ymd(2023, 12, 31));
+    let days: Vec<_> = SANDWICH.holidays(year).collect();
+    assert!(
+        start.elapsed() < Duration::from_secs(2),
+        "{:?}",
+        start.elapsed()
+    );
+
+    // Only Wednesday 3 May lies between two holidays; the other bridges
+    // border one another at most.
+    assert_eq!(
+        days,
+        [
+            Date::ymd(2023, 5, 2),
+            Date::ymd(2023, 5, 3),
+            Date::ymd(2023, 5, 4)
+        ]
+    );
+}
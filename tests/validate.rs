@@ -0,0 +1,251 @@
+use business_calendar::markets::fr::settlement::FR_SETTLEMENT;
+use business_calendar::{
+    Date, Diagnostic, HolidayRule, NamedRule, Problem, RelativeCondition, RuleRef, StaticCalendar,
+    YearCycle, YearRange,
+};
+use chrono::Weekday;
+
+mod common;
+
+use common::{SHIPPED, SHIPPED_DERIVED};
+
+const WEEKEND: [Weekday; 2] = [Weekday::Sat, Weekday::Sun];
+
+fn problems(rules: &[NamedRule]) -> Vec<(usize, Problem)> {
+    StaticCalendar::new("Test", WEEKEND, rules)
+        .validate()
+        .into_iter()
+        .map(|d| (d.index, d.problem))
+        .collect()
+}
+
+#[test]
+fn shipped_calendars_are_clean() {
+    for (path, _, cal) in SHIPPED {
+        assert_eq!(cal.validate(), vec![], "{path}");
+    }
+    for (path, _, cal) in SHIPPED_DERIVED {
+        assert_eq!(cal.validate(), vec![], "{path}");
+    }
+}
+
+#[test]
+fn french_movable_feasts_follow_easter() {
+    // Easter Sunday 2024 was 31 March.
+    assert!(FR_SETTLEMENT.is_holiday(Date::ymd(2024, 5, 9)));
+    assert!(FR_SETTLEMENT.is_holiday(Date::ymd(2024, 5, 20)));
+    assert!(FR_SETTLEMENT.is_business_day(Date::ymd(2024, 5, 10)));
+    assert!(FR_SETTLEMENT.is_business_day(Date::ymd(2024, 5, 21)));
+}
+
+#[test]
+fn reports_impossible_dates_and_nth() {
+    let rules = [
+        NamedRule::new(
+            "Leap Day",
+            HolidayRule::Fixed {
+                month: 2,
+                day: 30,
+                years: YearRange::always(),
+            },
+        ),
+        NamedRule::new(
+            "Sixth Monday",
+            HolidayRule::NthWeekday {
+                month: 13,
+                weekday: Weekday::Mon,
+                nth: 6,
+                years: YearRange::always(),
+            },
+        ),
+        NamedRule::new(
+            "Bad One-Off",
+            HolidayRule::OneOff {
+                year: 2023,
+                month: 2,
+                day: 29,
+            },
+        ),
+    ];
+    assert_eq!(
+        problems(&rules),
+        vec![
+            (
+                0,
+                Problem::ImpossibleDate {
+                    year: None,
+                    month: 2,
+                    day: 30
+                }
+            ),
+            (1, Problem::ImpossibleMonth(13)),
+            (1, Problem::NthOutOfRange(6)),
+            (
+                2,
+                Problem::ImpossibleDate {
+                    year: Some(2023),
+                    month: 2,
+                    day: 29
+                }
+            ),
+        ]
+    );
+}
+
+#[test]
+fn reports_inverted_ranges_and_easter_beyond_tables() {
+    let rules = [
+        NamedRule::new(
+            "Backwards",
+            HolidayRule::Fixed {
+                month: 7,
+                day: 4,
+                years: YearRange::between(2000, 1990),
+            },
+        ),
+        NamedRule::new(
+            "Good Friday",
+            HolidayRule::EasterOffset {
+                western: true,
+                offset: -3,
+                years: YearRange::always(),
+            },
+        ),
+    ];
+    assert_eq!(
+        problems(&rules),
+        vec![
+            (
+                0,
+                Problem::InvertedYearRange(YearRange::between(2000, 1990))
+            ),
+            (1, Problem::EasterOutOfRange(YearRange::always())),
+        ]
+    );
+}
+
+#[test]
+fn reports_duplicates_and_weekend_one_offs() {
+    let rules = [
+        NamedRule::new(
+            "Christmas Day",
+            HolidayRule::Fixed {
+                month: 12,
+                day: 25,
+                years: YearRange::always(),
+            },
+        ),
+        NamedRule::new(
+            "Christmas",
+            HolidayRule::Fixed {
+                month: 12,
+                day: 25,
+                years: YearRange::from(2000),
+            },
+        ),
+        NamedRule::new(
+            "Closure",
+            HolidayRule::OneOff {
+                year: 2023,
+                month: 12,
+                day: 25,
+            },
+        ),
+        // A Saturday.
+        NamedRule::new(
+            "Saturday Closure",
+            HolidayRule::OneOff {
+                year: 2023,
+                month: 12,
+                day: 30,
+            },
+        ),
+    ];
+    let diagnostics = StaticCalendar::new("Test", WEEKEND, &rules).validate();
+    assert_eq!(
+        diagnostics,
+        vec![
+            Diagnostic {
+                index: 1,
+                rule: "Christmas",
+                problem: Problem::Duplicate {
+                    index: 0,
+                    rule: "Christmas Day"
+                },
+            },
+            Diagnostic {
+                index: 2,
+                rule: "Closure",
+                problem: Problem::Duplicate {
+                    index: 0,
+                    rule: "Christmas Day"
+                },
+            },
+            Diagnostic {
+                index: 3,
+                rule: "Saturday Closure",
+                problem: Problem::OneOffOnWeekend(Date::ymd(2023, 12, 30)),
+            },
+        ]
+    );
+    assert_eq!(
+        diagnostics[1].to_string(),
+        "rule #2 \"Closure\": matches the same days as rule #0 \"Christmas Day\""
+    );
+}
+
+#[test]
+fn reports_empty_year_cycles_and_relative_cycles() {
+    let relative = |name, base| {
+        NamedRule::new(
+            name,
+            HolidayRule::Relative {
+                base,
+                offset: 1,
+                condition: RelativeCondition::Always,
+                years: YearRange::always(),
+            },
+        )
+    };
+    let rules = [
+        NamedRule::new(
+            "Census Day",
+            HolidayRule::Fixed {
+                month: 4,
+                day: 1,
+                years: YearRange::always(),
+            },
+        )
+        .every(YearCycle {
+            modulus: 0,
+            remainder: 0,
+        }),
+        relative("A", RuleRef::Name("B")),
+        relative("B", RuleRef::Name("A")),
+        relative("Self", RuleRef::Index(3)),
+        relative("Day After A", RuleRef::Name("A")),
+    ];
+    assert_eq!(
+        problems(&rules),
+        vec![
+            (
+                0,
+                Problem::InvalidYearCycle(YearCycle {
+                    modulus: 0,
+                    remainder: 0
+                })
+            ),
+            (1, Problem::RelativeCycle),
+            (2, Problem::RelativeCycle),
+            (3, Problem::RelativeCycle),
+        ]
+    );
+    assert_eq!(
+        Problem::InvalidYearCycle(YearCycle {
+            modulus: -4,
+            remainder: 0
+        })
+        .to_string(),
+        "year cycle modulus -4 is not positive"
+    );
+}
@@ -0,0 +1,80 @@
+use business_calendar::{Date, HolidayRule, HolidayRuleLike, YearRange};
+use chrono::Weekday;
+
+#[test]
+fn victoria_day_is_monday_before_25_may() {
+    let rule = HolidayRule::WeekdayOnOrBefore {
+        month: 5,
+        day: 24,
+        weekday: Weekday::Mon,
+        offset: 0,
+        years: YearRange::always(),
+    };
+    assert_eq!(rule.actual_date(2024), Some(Date::ymd(2024, 5, 20)));
+    assert_eq!(rule.actual_date(2027), Some(Date::ymd(2027, 5, 24)));
+    assert!(rule.matches(Date::ymd(2025, 5, 19), 2025));
+    assert!(!rule.matches(Date::ymd(2025, 5, 26), 2025));
+}
+
+#[test]
+fn midsummer_eve_is_friday_between_19_and_25_june() {
+    let rule = HolidayRule::WeekdayAfter {
+        month: 6,
+        day: 19,
+        weekday: Weekday::Sat,
+        offset: -1,
+        years: YearRange::always(),
+    };
+    for year in 2000..2040 {
+        let d = rule.actual_date(year).unwrap();
+        assert_eq!(d.weekday(), Weekday::Fri);
+        assert!((19..=25).contains(&d.day()), "{year}: {d:?}");
+    }
+    assert_eq!(rule.actual_date(2025), Some(Date::ymd(2025, 6, 20)));
+    assert_eq!(rule.observed_date(2025), Some(Date::ymd(2025, 6, 20)));
+}
+
+#[test]
+fn election_day_is_tuesday_after_first_monday_in_november() {
+    let rule = HolidayRule::WeekdayOnOrAfter {
+        month: 11,
+        day: 2,
+        weekday: Weekday::Tue,
+        offset: 0,
+        years: YearRange::from(1900),
+    };
+    assert_eq!(rule.actual_date(2020), Some(Date::ymd(2020, 11, 3)));
+    assert_eq!(rule.actual_date(2022), Some(Date::ymd(2022, 11, 8)));
+    assert_eq!(rule.actual_date(2024), Some(Date::ymd(2024, 11, 5)));
+    assert_eq!(rule.actual_date(1899), None);
+}
+
+#[test]
+fn offsets_may_cross_the_year_boundary() {
+    // The day after the last Friday of December (2027-12-31 is a Friday).
+    let rule = HolidayRule::WeekdayOnOrBefore {
+        month: 12,
+        day: 31,
+        weekday: Weekday::Fri,
+        offset: 1,
+        years: YearRange::always(),
+    };
+    let date = Date::ymd(2028, 1, 1);
+    assert_eq!(rule.actual_date(2027), Some(date));
+    assert!(rule.matches(date, 2028));
+    assert!(rule.holidays_in_year(2028).contains(&date));
+    assert!(!rule.holidays_in_year(2027).contains(&date));
+
+    // The day before the first Monday on or after 1 January.
+    let rule = HolidayRule::WeekdayOnOrAfter {
+        month: 1,
+        day: 1,
+        weekday: Weekday::Mon,
+        offset: -1,
+        years: YearRange::always(),
+    };
+    let date = Date::ymd(2028, 12, 31);
+    assert_eq!(rule.actual_date(2029), Some(date));
+    assert!(rule.matches(date, 2028));
+    assert!(rule.holidays_in_year(2028).contains(&date));
+}
@@ -0,0 +1,134 @@
+use business_calendar::rules::{DateTable, HolidayRule, NamedRule};
+use business_calendar::static_calendar::StaticCalendar;
+use business_calendar::{Calendar, Date, DateRange};
+use chrono::Weekday;
+
+// Spring Festival 2025 in mainland China: closed 28 Jan - 4 Feb, with
+// Sunday 26 Jan and Saturday 8 Feb worked in exchange.
+static RULES: &[NamedRule] = &[
+    NamedRule::new(
+        "Spring Festival",
+        HolidayRule::Dates(DateTable::new(&[
+            20250128, 20250129, 20250130, 20250131, 20250203, 20250204,
+        ])),
+    ),
+    NamedRule::working_day(
+        "Spring Festival make-up working day",
+        HolidayRule::Dates(DateTable::new(&[20250126, 20250208])),
+    ),
+    // A working-day rule wins over a holiday rule on the same date.
+    NamedRule::new(
+        "Cancelled Holiday",
+        HolidayRule::OneOff {
+            year: 2025,
+            month: 3,
+            day: 5,
+        },
+    ),
+    NamedRule::working_day(
+        "Cancelled Holiday reopening",
+        HolidayRule::OneOff {
+            year: 2025,
+            month: 3,
+            day: 5,
+        },
+    ),
+];
+
+static CN: StaticCalendar = StaticCalendar::new("CN", [Weekday::Sat, Weekday::Sun], RULES);
+
+#[test]
+fn make_up_days_are_business_days() {
+    for d in [
+        Date::ymd(2025, 1, 26),
+        Date::ymd(2025, 2, 8),
+        Date::ymd(2025, 3, 5),
+    ] {
+        assert!(CN.is_business_day(d), "{d:?}");
+        assert!(!CN.is_weekend(d));
+        assert!(!CN.is_holiday(d));
+    }
+    // Neighbouring weekend days are untouched.
+    assert!(CN.is_weekend(Date::ymd(2025, 1, 25)));
+    assert!(CN.is_weekend(Date::ymd(2025, 2, 9)));
+    assert!(CN.is_holiday(Date::ymd(2025, 1, 28)));
+}
+
+#[test]
+fn predicates_stay_consistent() {
+    let range = DateRange::inclusive(Date::ymd(2025, 1, 1), Date::ymd(2025, 3, 31));
+    let mut d = range.start();
+    while range.contains(d) {
+        let (bd, we, hol) = (CN.is_business_day(d), CN.is_weekend(d), CN.is_holiday(d));
+        // Exactly one of business day, weekend and holiday.
+        assert_eq!(bd as u8 + we as u8 + hol as u8, 1, "{d:?}");
+        assert_eq!(Calendar::is_holiday(&CN, d), hol);
+        d = d.add_days(1);
+    }
+}
+
+#[test]
+fn iterators_include_make_up_days() {
+    let feb = DateRange::month_of(Date::ymd(2025, 2, 1));
+    let days: Vec<_> = CN.business_days(feb).collect();
+    assert!(days.contains(&Date::ymd(2025, 2, 8)));
+    assert_eq!(days.len(), 19);
+    assert_eq!(
+        CN.business_days(feb).rev().find(|d| d.day() < 10),
+        Some(Date::ymd(2025, 2, 8))
+    );
+    assert_eq!(CN.holidays(feb).count(), 2);
+}
+
+#[test]
+fn explain_reports_the_override() {
+    let e = CN.explain(Date::ymd(2025, 2, 8));
+    assert!(e.business_day);
+    assert!(!e.weekend);
+    assert_eq!(e.overrides.len(), 1);
+    assert_eq!(
+        e.overrides[0].description,
+        "Spring Festival make-up working day"
+    );
+    assert!(e.overrides[0].business_day);
+
+    let e = CN.explain(Date::ymd(2025, 3, 5));
+    assert!(e.business_day);
+    assert_eq!(e.holidays[0].name, "Cancelled Holiday");
+    assert_eq!(e.overrides[0].description, "Cancelled Holiday reopening");
+}
+
+#[test]
+fn working_day_relative_rule_landing_on_a_weekend() {
+    use business_calendar::rules::{RelativeCondition, RuleRef};
+    use business_calendar::YearRange;
+
+    static RULES: &[NamedRule] = &[
+        // Friday 3 January 2025.
+        NamedRule::new(
+            "Base",
+            HolidayRule::OneOff {
+                year: 2025,
+                month: 1,
+                day: 3,
+            },
+        ),
+        NamedRule::working_day(
+            "Make-up",
+            HolidayRule::Relative {
+                base: RuleRef::Name("Base"),
+                offset: 1,
+                condition: RelativeCondition::Weekday,
+                years: YearRange::always(),
+            },
+        ),
+    ];
+    static CAL: StaticCalendar =
+        StaticCalendar::new("Make-up", [Weekday::Sat, Weekday::Sun], RULES);
+
+    // The make-up day would be Saturday 4 January, so it does not apply.
+    let saturday = Date::ymd(2025, 1, 4);
+    assert!(CAL.is_weekend(saturday));
+    assert!(!CAL.is_business_day(saturday));
+    assert!(!CAL.is_business_day(Date::ymd(2025, 1, 3)));
+}
@@ -0,0 +1,59 @@
+use business_calendar::markets::us::{USMarket, UnitedStates};
+use business_calendar::{Calendar, Date, HolidayRule, NamedRule, YearCycle, YearRange};
+
+#[test]
+fn nyse_election_day_closures() {
+    let nyse = UnitedStates::new(USMarket::NYSE);
+    // Every year until 1968.
+    assert!(!nyse.is_business_day(Date::ymd(1967, 11, 7)));
+    assert!(!nyse.is_business_day(Date::ymd(1968, 11, 5)));
+    // Presidential years only from 1969 to 1980.
+    assert!(nyse.is_business_day(Date::ymd(1970, 11, 3)));
+    assert!(!nyse.is_business_day(Date::ymd(1972, 11, 7)));
+    assert!(nyse.is_business_day(Date::ymd(1978, 11, 7)));
+    assert!(!nyse.is_business_day(Date::ymd(1980, 11, 4)));
+    // Open ever since.
+    assert!(nyse.is_business_day(Date::ymd(1984, 11, 6)));
+    assert!(nyse.is_business_day(Date::ymd(2024, 11, 5)));
+
+    assert_eq!(
+        nyse.explain(Date::ymd(1976, 11, 2)).holidays[0].name,
+        "Election Day"
+    );
+}
+
+#[test]
+fn cycles_combine_with_any_rule() {
+    let inauguration = NamedRule::new(
+        "Inauguration Day",
+        HolidayRule::Fixed {
+            month: 1,
+            day: 20,
+            years: YearRange::from(1937),
+        },
+    )
+    .every(YearCycle::new(4, 1));
+
+    assert!(inauguration.is_holiday(Date::ymd(2025, 1, 20), 2025));
+    assert!(!inauguration.is_holiday(Date::ymd(2026, 1, 20), 2026));
+    assert!(!inauguration.is_holiday(Date::ymd(1933, 1, 20), 1933));
+    assert!(inauguration.occurrence(2029).is_some());
+    assert!(inauguration.occurrence(2028).is_none());
+
+    assert!(YearCycle::new(4, 3).contains(-1));
+}
+
+#[test]
+#[should_panic(expected = "year cycle modulus must be positive")]
+fn zero_modulus_is_rejected() {
+    YearCycle::new(0, 0);
+}
+
+#[test]
+fn zero_modulus_literal_never_matches() {
+    let cycle = YearCycle {
+        modulus: 0,
+        remainder: 0,
+    };
+    assert!(!cycle.contains(2024));
+}